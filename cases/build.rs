@@ -0,0 +1,93 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+// Generates the vsetvli/vsetivli dispatch included by src/intrinsic_setvl.rs.
+// vtypei and the vsetivli AVL are immediates, so every combination needs its
+// own arm. Extending a table below extends the dispatch.
+
+const SEWS: [u64; 8] = [8, 16, 32, 64, 128, 256, 512, 1024];
+const LMULS: [(i64, &str); 7] = [
+    (-8, "mf8"),
+    (-4, "mf4"),
+    (-2, "mf2"),
+    (1, "m1"),
+    (2, "m2"),
+    (4, "m4"),
+    (8, "m8"),
+];
+// uimm5
+const IMMEDIATE_AVLS: u64 = 32;
+
+// `match lmul { .. match sew { .. } }` with one `asm(sew, lmul)` per arm
+fn lmul_sew_match(out: &mut String, asm: &dyn Fn(u64, &str) -> String) {
+    writeln!(out, "match lmul {{").unwrap();
+    for (lmul, lmul_name) in LMULS.iter() {
+        writeln!(out, "{} => match sew {{", lmul).unwrap();
+        for sew in SEWS.iter() {
+            writeln!(out, "{} => {{", sew).unwrap();
+            writeln!(out, "{};", asm(*sew, lmul_name)).unwrap();
+            writeln!(out, "vl").unwrap();
+            writeln!(out, "}}").unwrap();
+        }
+        writeln!(out, "_ => panic!(\"Abort\"),").unwrap();
+        writeln!(out, "}},").unwrap();
+    }
+    writeln!(out, "_ => panic!(\"Abort\"),").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn gen_vsetvli(out: &mut String) {
+    writeln!(out, "#[inline(never)]").unwrap();
+    writeln!(
+        out,
+        "pub fn v_setvli(avl: u64, sew: u64, lmul: i64) -> u64 {{"
+    )
+    .unwrap();
+    writeln!(out, "unsafe {{").unwrap();
+    writeln!(out, "let mut vl: u64;").unwrap();
+    lmul_sew_match(out, &|sew, lmul| {
+        format!(
+            "rvv_asm!(\"mv t1, {{0}}\", \"vsetvli t0, t1, e{}, {}\", \"mv {{1}}, t0\", in (reg) avl, out (reg) vl)",
+            sew, lmul
+        )
+    });
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn gen_vsetivli(out: &mut String) {
+    writeln!(out, "#[inline(never)]").unwrap();
+    writeln!(
+        out,
+        "pub fn v_setivli(avl: u64, sew: u64, lmul: i64) -> u64 {{"
+    )
+    .unwrap();
+    writeln!(out, "unsafe {{").unwrap();
+    writeln!(out, "let mut vl: u64;").unwrap();
+    writeln!(out, "match avl {{").unwrap();
+    for avl in 0..IMMEDIATE_AVLS {
+        writeln!(out, "{} => {{", avl).unwrap();
+        lmul_sew_match(out, &|sew, lmul| {
+            format!(
+                "rvv_asm!(\"vsetivli t0, {}, e{}, {}\", \"mv {{0}}, t0\", out (reg) vl)",
+                avl, sew, lmul
+            )
+        });
+        writeln!(out, "}}").unwrap();
+    }
+    writeln!(out, "_ => panic!(\"Abort\"),").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn main() {
+    let mut out = String::new();
+    gen_vsetvli(&mut out);
+    gen_vsetivli(&mut out);
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("intrinsic_setvl.rs");
+    fs::write(path, out).unwrap();
+    println!("cargo:rerun-if-changed=build.rs");
+}
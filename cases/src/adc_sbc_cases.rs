@@ -6,6 +6,8 @@ use rvv_testcases::runner::{
     run_template_m_vx, run_template_m_vxm, run_template_v_vim, run_template_v_vvm,
     run_template_v_vxm, MaskType,
 };
+use rvv_testcases::rvv_asm_x;
+use rvv_testcases::rvv_op_i;
 
 // use ckb_std::syscalls::debug;
 // use rvv_testcases::log;
@@ -176,7 +178,7 @@ fn test_vadc_vxm() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vadc.vxm v24, v8, t0, v0", in (reg) x);
+            rvv_asm_x!("vadc.vxm v24, v8, t0, v0", x);
         }
     }
     run_template_v_vxm(expected_op_adc_vxm, rvv_op, "vadc.vxm");
@@ -188,109 +190,7 @@ fn expected_op_adc_vim(lhs: &[u8], x: i64, result: &mut [u8], mask: bool) {
 fn test_vadc_vim() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => {
-                    rvv_asm!("vadc.vim v24, v8, -16, v0");
-                }
-                -15 => {
-                    rvv_asm!("vadc.vim v24, v8, -15, v0");
-                }
-                -14 => {
-                    rvv_asm!("vadc.vim v24, v8, -14, v0");
-                }
-                -13 => {
-                    rvv_asm!("vadc.vim v24, v8, -13, v0");
-                }
-                -12 => {
-                    rvv_asm!("vadc.vim v24, v8, -12, v0");
-                }
-                -11 => {
-                    rvv_asm!("vadc.vim v24, v8, -11, v0");
-                }
-                -10 => {
-                    rvv_asm!("vadc.vim v24, v8, -10, v0");
-                }
-                -9 => {
-                    rvv_asm!("vadc.vim v24, v8, -9, v0");
-                }
-                -8 => {
-                    rvv_asm!("vadc.vim v24, v8, -8, v0");
-                }
-                -7 => {
-                    rvv_asm!("vadc.vim v24, v8, -7, v0");
-                }
-                -6 => {
-                    rvv_asm!("vadc.vim v24, v8, -6, v0");
-                }
-                -5 => {
-                    rvv_asm!("vadc.vim v24, v8, -5, v0");
-                }
-                -4 => {
-                    rvv_asm!("vadc.vim v24, v8, -4, v0");
-                }
-                -3 => {
-                    rvv_asm!("vadc.vim v24, v8, -3, v0");
-                }
-                -2 => {
-                    rvv_asm!("vadc.vim v24, v8, -2, v0");
-                }
-                -1 => {
-                    rvv_asm!("vadc.vim v24, v8, -1, v0");
-                }
-                0 => {
-                    rvv_asm!("vadc.vim v24, v8, 0, v0");
-                }
-                1 => {
-                    rvv_asm!("vadc.vim v24, v8, 1, v0");
-                }
-                2 => {
-                    rvv_asm!("vadc.vim v24, v8, 2, v0");
-                }
-                3 => {
-                    rvv_asm!("vadc.vim v24, v8, 3, v0");
-                }
-                4 => {
-                    rvv_asm!("vadc.vim v24, v8, 4, v0");
-                }
-                5 => {
-                    rvv_asm!("vadc.vim v24, v8, 5, v0");
-                }
-                6 => {
-                    rvv_asm!("vadc.vim v24, v8, 6, v0");
-                }
-                7 => {
-                    rvv_asm!("vadc.vim v24, v8, 7, v0");
-                }
-                8 => {
-                    rvv_asm!("vadc.vim v24, v8, 8, v0");
-                }
-                9 => {
-                    rvv_asm!("vadc.vim v24, v8, 9, v0");
-                }
-                10 => {
-                    rvv_asm!("vadc.vim v24, v8, 10, v0");
-                }
-                11 => {
-                    rvv_asm!("vadc.vim v24, v8, 11, v0");
-                }
-                12 => {
-                    rvv_asm!("vadc.vim v24, v8, 12, v0");
-                }
-                13 => {
-                    rvv_asm!("vadc.vim v24, v8, 13, v0");
-                }
-                14 => {
-                    rvv_asm!("vadc.vim v24, v8, 14, v0");
-                }
-                15 => {
-                    rvv_asm!("vadc.vim v24, v8, 15, v0");
-                }
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vadc.vim", imm, carry);
     }
     run_template_v_vim(expected_op_adc_vim, rvv_op, "vadc.vim");
 }
@@ -467,7 +367,7 @@ fn test_vmadc_vxm() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vmadc.vxm v24, v8, t0, v0", in (reg) x);
+            rvv_asm_x!("vmadc.vxm v24, v8, t0, v0", x);
         }
     }
 
@@ -480,109 +380,7 @@ fn expected_op_madc_vim(lhs: &[u8], x: i64, result: &mut bool, mask: bool) {
 fn test_vmadc_vim() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => {
-                    rvv_asm!("vmadc.vim v24, v8, -16, v0");
-                }
-                -15 => {
-                    rvv_asm!("vmadc.vim v24, v8, -15, v0");
-                }
-                -14 => {
-                    rvv_asm!("vmadc.vim v24, v8, -14, v0");
-                }
-                -13 => {
-                    rvv_asm!("vmadc.vim v24, v8, -13, v0");
-                }
-                -12 => {
-                    rvv_asm!("vmadc.vim v24, v8, -12, v0");
-                }
-                -11 => {
-                    rvv_asm!("vmadc.vim v24, v8, -11, v0");
-                }
-                -10 => {
-                    rvv_asm!("vmadc.vim v24, v8, -10, v0");
-                }
-                -9 => {
-                    rvv_asm!("vmadc.vim v24, v8, -9, v0");
-                }
-                -8 => {
-                    rvv_asm!("vmadc.vim v24, v8, -8, v0");
-                }
-                -7 => {
-                    rvv_asm!("vmadc.vim v24, v8, -7, v0");
-                }
-                -6 => {
-                    rvv_asm!("vmadc.vim v24, v8, -6, v0");
-                }
-                -5 => {
-                    rvv_asm!("vmadc.vim v24, v8, -5, v0");
-                }
-                -4 => {
-                    rvv_asm!("vmadc.vim v24, v8, -4, v0");
-                }
-                -3 => {
-                    rvv_asm!("vmadc.vim v24, v8, -3, v0");
-                }
-                -2 => {
-                    rvv_asm!("vmadc.vim v24, v8, -2, v0");
-                }
-                -1 => {
-                    rvv_asm!("vmadc.vim v24, v8, -1, v0");
-                }
-                0 => {
-                    rvv_asm!("vmadc.vim v24, v8, 0, v0");
-                }
-                1 => {
-                    rvv_asm!("vmadc.vim v24, v8, 1, v0");
-                }
-                2 => {
-                    rvv_asm!("vmadc.vim v24, v8, 2, v0");
-                }
-                3 => {
-                    rvv_asm!("vmadc.vim v24, v8, 3, v0");
-                }
-                4 => {
-                    rvv_asm!("vmadc.vim v24, v8, 4, v0");
-                }
-                5 => {
-                    rvv_asm!("vmadc.vim v24, v8, 5, v0");
-                }
-                6 => {
-                    rvv_asm!("vmadc.vim v24, v8, 6, v0");
-                }
-                7 => {
-                    rvv_asm!("vmadc.vim v24, v8, 7, v0");
-                }
-                8 => {
-                    rvv_asm!("vmadc.vim v24, v8, 8, v0");
-                }
-                9 => {
-                    rvv_asm!("vmadc.vim v24, v8, 9, v0");
-                }
-                10 => {
-                    rvv_asm!("vmadc.vim v24, v8, 10, v0");
-                }
-                11 => {
-                    rvv_asm!("vmadc.vim v24, v8, 11, v0");
-                }
-                12 => {
-                    rvv_asm!("vmadc.vim v24, v8, 12, v0");
-                }
-                13 => {
-                    rvv_asm!("vmadc.vim v24, v8, 13, v0");
-                }
-                14 => {
-                    rvv_asm!("vmadc.vim v24, v8, 14, v0");
-                }
-                15 => {
-                    rvv_asm!("vmadc.vim v24, v8, 15, v0");
-                }
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vmadc.vim", imm, carry);
     }
     run_template_m_vim(expected_op_madc_vim, rvv_op, "vmadc.vim");
 }
@@ -607,7 +405,7 @@ fn test_vmadc_vx() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vmadc.vx v24, v8, t0", in (reg) x);
+            rvv_asm_x!("vmadc.vx v24, v8, t0", x);
         }
     }
 
@@ -620,109 +418,7 @@ fn test_vmadc_vi() {
     }
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => {
-                    rvv_asm!("vmadc.vi v24, v8, -16");
-                }
-                -15 => {
-                    rvv_asm!("vmadc.vi v24, v8, -15");
-                }
-                -14 => {
-                    rvv_asm!("vmadc.vi v24, v8, -14");
-                }
-                -13 => {
-                    rvv_asm!("vmadc.vi v24, v8, -13");
-                }
-                -12 => {
-                    rvv_asm!("vmadc.vi v24, v8, -12");
-                }
-                -11 => {
-                    rvv_asm!("vmadc.vi v24, v8, -11");
-                }
-                -10 => {
-                    rvv_asm!("vmadc.vi v24, v8, -10");
-                }
-                -9 => {
-                    rvv_asm!("vmadc.vi v24, v8, -9");
-                }
-                -8 => {
-                    rvv_asm!("vmadc.vi v24, v8, -8");
-                }
-                -7 => {
-                    rvv_asm!("vmadc.vi v24, v8, -7");
-                }
-                -6 => {
-                    rvv_asm!("vmadc.vi v24, v8, -6");
-                }
-                -5 => {
-                    rvv_asm!("vmadc.vi v24, v8, -5");
-                }
-                -4 => {
-                    rvv_asm!("vmadc.vi v24, v8, -4");
-                }
-                -3 => {
-                    rvv_asm!("vmadc.vi v24, v8, -3");
-                }
-                -2 => {
-                    rvv_asm!("vmadc.vi v24, v8, -2");
-                }
-                -1 => {
-                    rvv_asm!("vmadc.vi v24, v8, -1");
-                }
-                0 => {
-                    rvv_asm!("vmadc.vi v24, v8, 0");
-                }
-                1 => {
-                    rvv_asm!("vmadc.vi v24, v8, 1");
-                }
-                2 => {
-                    rvv_asm!("vmadc.vi v24, v8, 2");
-                }
-                3 => {
-                    rvv_asm!("vmadc.vi v24, v8, 3");
-                }
-                4 => {
-                    rvv_asm!("vmadc.vi v24, v8, 4");
-                }
-                5 => {
-                    rvv_asm!("vmadc.vi v24, v8, 5");
-                }
-                6 => {
-                    rvv_asm!("vmadc.vi v24, v8, 6");
-                }
-                7 => {
-                    rvv_asm!("vmadc.vi v24, v8, 7");
-                }
-                8 => {
-                    rvv_asm!("vmadc.vi v24, v8, 8");
-                }
-                9 => {
-                    rvv_asm!("vmadc.vi v24, v8, 9");
-                }
-                10 => {
-                    rvv_asm!("vmadc.vi v24, v8, 10");
-                }
-                11 => {
-                    rvv_asm!("vmadc.vi v24, v8, 11");
-                }
-                12 => {
-                    rvv_asm!("vmadc.vi v24, v8, 12");
-                }
-                13 => {
-                    rvv_asm!("vmadc.vi v24, v8, 13");
-                }
-                14 => {
-                    rvv_asm!("vmadc.vi v24, v8, 14");
-                }
-                15 => {
-                    rvv_asm!("vmadc.vi v24, v8, 15");
-                }
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vmadc.vi", imm, unmasked);
     }
 
     run_template_m_vi(exp_op, rvv_op, false, "vmadc.vi")
@@ -894,7 +590,7 @@ fn test_vsbc_vxm() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vsbc.vxm v24, v8, t0, v0", in (reg) x);
+            rvv_asm_x!("vsbc.vxm v24, v8, t0, v0", x);
         }
     }
     run_template_v_vxm(expected_op_sbc_vxm, rvv_op, "vsbc.vxm");
@@ -1072,7 +768,7 @@ fn test_vmsbc_vxm() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vmsbc.vxm v24, v8, t0, v0", in (reg) x);
+            rvv_asm_x!("vmsbc.vxm v24, v8, t0, v0", x);
         }
     }
 
@@ -1099,7 +795,7 @@ fn test_vmsbc_vx() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vmsbc.vx v24, v8, t0", in (reg) x);
+            rvv_asm_x!("vmsbc.vx v24, v8, t0", x);
         }
     }
 
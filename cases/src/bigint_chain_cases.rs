@@ -0,0 +1,215 @@
+use alloc::vec::Vec;
+use ckb_std::syscalls::debug;
+use core::arch::asm;
+use num_bigint::BigUint;
+use num_traits::One;
+use rand::Rng;
+use rvv_asm::rvv_asm;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::digest::update_digest;
+use rvv_testcases::intrinsic::{vle_v16, vle_v8, vse_v24, vsetvl};
+use rvv_testcases::log;
+use rvv_testcases::misc::{get_bit_in_slice, is_full, is_verbose, VLEN};
+use rvv_testcases::rng::BestNumberRng;
+
+// every number has at least this many bits
+const BITS: usize = 2112;
+// numbers added at once
+const COUNT: u64 = 16;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Chain {
+    Add,
+    Sub,
+    // a < b, only the borrow chain
+    Less,
+}
+
+// Numbers are sliced by limb: register group j holds limb j of `vl` numbers,
+// so `a` and `b` are `limbs` vectors of `vl` elements each. The carry (or
+// borrow) is kept in v0 between the limbs, the last one is returned as a mask.
+fn run_chain(
+    chain: Chain,
+    sew: u64,
+    limbs: usize,
+    a: &[u8],
+    b: &[u8],
+    result: &mut [u8],
+) -> Vec<u8> {
+    let len = a.len() / limbs;
+    unsafe {
+        rvv_asm!("vmxor.mm v0, v0, v0");
+    }
+    for j in 0..limbs {
+        let range = j * len..(j + 1) * len;
+        vle_v8(sew, &a[range.clone()]);
+        vle_v16(sew, &b[range.clone()]);
+        unsafe {
+            match chain {
+                Chain::Add => {
+                    rvv_asm!(
+                        "vadc.vvm v24, v8, v16, v0",
+                        "vmadc.vvm v4, v8, v16, v0",
+                        "vmand.mm v0, v4, v4"
+                    );
+                }
+                Chain::Sub => {
+                    rvv_asm!(
+                        "vsbc.vvm v24, v8, v16, v0",
+                        "vmsbc.vvm v4, v8, v16, v0",
+                        "vmand.mm v0, v4, v4"
+                    );
+                }
+                Chain::Less => {
+                    rvv_asm!("vmsbc.vvm v4, v8, v16, v0", "vmand.mm v0, v4, v4");
+                }
+            }
+        }
+        if chain != Chain::Less {
+            vse_v24(sew, &mut result[range]);
+        }
+    }
+    let mut carry = [0u8; VLEN / 8];
+    unsafe {
+        rvv_asm!("mv t0, {}", "vsm.v v0, (t0)", in (reg) carry.as_mut_ptr());
+    }
+    update_digest(&carry);
+    carry.to_vec()
+}
+
+fn get_number(buf: &[u8], k: usize, vl: usize, sew_bytes: usize) -> BigUint {
+    let mut bytes: Vec<u8> = Vec::new();
+    for limb in buf.chunks(vl * sew_bytes) {
+        bytes.extend_from_slice(&limb[k * sew_bytes..(k + 1) * sew_bytes]);
+    }
+    BigUint::from_bytes_le(&bytes)
+}
+
+// `n` modulo 2^bits as `bits / 8` little-endian bytes
+fn to_bytes(n: &BigUint, bits: usize) -> Vec<u8> {
+    let mut bytes = n.to_bytes_le();
+    bytes.resize(bits / 8, 0);
+    bytes
+}
+
+fn check(
+    chain: Chain,
+    sew: u64,
+    lmul: i64,
+    k: usize,
+    expected: &[u8],
+    result: &[u8],
+    a: &BigUint,
+    b: &BigUint,
+) {
+    if expected != result {
+        let desc = match chain {
+            Chain::Add => "add",
+            Chain::Sub => "sub",
+            Chain::Less => "less than",
+        };
+        log!(
+            "[describe = multi-precision {}] unexpected values found at number {}, sew = {}, lmul = {}",
+            desc,
+            k,
+            sew,
+            lmul
+        );
+        log!("-a: {:X}", a);
+        log!("-b: {:X}", b);
+        log!("-expected: {:0>2X?}", expected);
+        log!("-result: {:0>2X?}", result);
+        panic!("Abort");
+    }
+}
+
+fn test_chain(sew: u64, lmul: i64) {
+    let vl = vsetvl(COUNT, sew, lmul) as usize;
+    if vl == 0 {
+        return;
+    }
+    if is_verbose() {
+        log!("run with sew = {}, lmul = {}, vl = {}", sew, lmul, vl);
+    }
+    let sew_bytes = (sew / 8) as usize;
+    let limbs = (BITS + sew as usize - 1) / sew as usize;
+    let bits = limbs * sew as usize;
+    let len = limbs * vl * sew_bytes;
+
+    let mut rng = BestNumberRng::default();
+    let mut a: Vec<u8> = Vec::new();
+    a.resize(len, 0);
+    rng.fill(&mut a[..]);
+    let mut b: Vec<u8> = Vec::new();
+    b.resize(len, 0);
+    rng.fill(&mut b[..]);
+    // carries (and borrows) which ripple through every limb:
+    // number 0 is 2^bits - 1 and 1, number 1 is 0 and 1
+    for limb in 0..limbs {
+        let base = limb * vl * sew_bytes;
+        a[base..base + sew_bytes].fill(0xFF);
+        b[base..base + sew_bytes].fill(0);
+        if vl > 1 {
+            a[base + sew_bytes..base + sew_bytes * 2].fill(0);
+            b[base + sew_bytes..base + sew_bytes * 2].fill(0);
+        }
+    }
+    b[0] = 1;
+    if vl > 1 {
+        b[sew_bytes] = 1;
+    }
+
+    let modulus = BigUint::one() << bits;
+    for chain in [Chain::Add, Chain::Sub, Chain::Less] {
+        let mut result: Vec<u8> = Vec::new();
+        result.resize(len, 0);
+        let carry = run_chain(chain, sew, limbs, &a, &b, &mut result);
+
+        for k in 0..vl {
+            let x = get_number(&a, k, vl, sew_bytes);
+            let y = get_number(&b, k, vl, sew_bytes);
+            let (value, carry_out) = match chain {
+                Chain::Add => {
+                    let sum = &x + &y;
+                    let carry_out = sum >= modulus;
+                    (sum % &modulus, carry_out)
+                }
+                Chain::Sub | Chain::Less => {
+                    if x >= y {
+                        (&x - &y, false)
+                    } else {
+                        (&modulus + &x - &y, true)
+                    }
+                }
+            };
+            if chain != Chain::Less {
+                let r = to_bytes(&get_number(&result, k, vl, sew_bytes), bits);
+                check(chain, sew, lmul, k, &to_bytes(&value, bits), &r, &x, &y);
+            }
+            let c = get_bit_in_slice(&carry, k);
+            check(chain, sew, lmul, k, &[carry_out as u8], &[c], &x, &y);
+        }
+    }
+    record_instruction("vadc.vvm", sew, lmul, false);
+    record_instruction("vmadc.vvm", sew, lmul, false);
+    record_instruction("vsbc.vvm", sew, lmul, false);
+    record_instruction("vmsbc.vvm", sew, lmul, false);
+}
+
+pub fn test_bigint_chain() {
+    let sews = if is_full() {
+        [8, 16, 32, 64, 128, 256, 512, 1024].to_vec()
+    } else {
+        [64, 256, 1024].to_vec()
+    };
+    let lmuls = if is_full() {
+        [-8, -4, -2, 1, 2, 4, 8].to_vec()
+    } else {
+        [-2, 1, 8].to_vec()
+    };
+    for sew in sews {
+        for lmul in lmuls.clone() {
+            test_chain(sew, lmul);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use ckb_std::syscalls::debug;
+
+use super::isa::{canonical_mnemonic, find_instruction, memory_eew, INSTRUCTIONS};
+use super::log;
+use super::misc::{is_coverage, VLEN};
+
+pub const SEWS: [u64; 8] = [8, 16, 32, 64, 128, 256, 512, 1024];
+pub const LMULS: [i64; 7] = [-8, -4, -2, 1, 2, 4, 8];
+
+struct CoverageEntry {
+    mnemonic: String,
+    // one bit per (sew, lmul, masked), see `cell_index`
+    cells: u128,
+}
+
+static mut COVERAGE: Vec<CoverageEntry> = Vec::new();
+
+fn cell_index(sew: u64, lmul: i64, masked: bool) -> Option<usize> {
+    let sew_index = SEWS.iter().position(|s| *s == sew)?;
+    let lmul_index = LMULS.iter().position(|l| *l == lmul)?;
+    Some((sew_index * LMULS.len() + lmul_index) * 2 + masked as usize)
+}
+
+fn lmul_name(lmul: i64) -> &'static str {
+    match lmul {
+        -8 => "mf8",
+        -4 => "mf4",
+        -2 => "mf2",
+        1 => "m1",
+        2 => "m2",
+        4 => "m4",
+        8 => "m8",
+        _ => panic!("Abort"),
+    }
+}
+
+// VLMAX >= 1
+fn is_legal_cell(sew: u64, lmul: i64) -> bool {
+    if lmul > 0 {
+        VLEN as u64 * lmul as u64 >= sew
+    } else {
+        VLEN as u64 / (-lmul) as u64 >= sew
+    }
+}
+
+// For memory instructions, `sew` is ignored and the EEW in the mnemonic is
+// recorded instead.
+pub fn record_instruction(mnemonic: &str, sew: u64, lmul: i64, masked: bool) {
+    if !is_coverage() {
+        return;
+    }
+    let sew = memory_eew(mnemonic).unwrap_or(sew);
+    let mnemonic = canonical_mnemonic(mnemonic);
+
+    let coverage = unsafe { &mut COVERAGE };
+    let index = match coverage.iter().position(|e| e.mnemonic == mnemonic) {
+        Some(index) => index,
+        None => {
+            coverage.push(CoverageEntry { mnemonic, cells: 0 });
+            coverage.len() - 1
+        }
+    };
+    if let Some(cell) = cell_index(sew, lmul, masked) {
+        coverage[index].cells |= 1u128 << cell;
+    }
+}
+
+pub fn print_coverage_report() {
+    let coverage = unsafe { &COVERAGE };
+
+    let mut untested: Vec<&str> = Vec::new();
+    for insn in INSTRUCTIONS.iter() {
+        if !coverage.iter().any(|e| e.mnemonic == insn.mnemonic) {
+            untested.push(insn.mnemonic);
+        }
+    }
+    log!(
+        "--Coverage: {}/{} instructions tested",
+        INSTRUCTIONS.len() - untested.len(),
+        INSTRUCTIONS.len()
+    );
+    log!("--Untested instructions: {}", untested.join(", "));
+
+    for entry in coverage.iter() {
+        let masked = match find_instruction(&entry.mnemonic) {
+            Some(insn) => insn.masked,
+            None => {
+                log!("--Unknown instruction: {}", entry.mnemonic);
+                continue;
+            }
+        };
+        let mut cells: Vec<String> = Vec::new();
+        for sew in SEWS {
+            for lmul in LMULS {
+                if !is_legal_cell(sew, lmul) {
+                    continue;
+                }
+                for m in [false, true] {
+                    if m && !masked {
+                        continue;
+                    }
+                    let cell = cell_index(sew, lmul, m).unwrap();
+                    if entry.cells & (1u128 << cell) == 0 {
+                        cells.push(format!(
+                            "e{}{}{}",
+                            sew,
+                            lmul_name(lmul),
+                            if m { ".t" } else { "" }
+                        ));
+                    }
+                }
+            }
+        }
+        if !cells.is_empty() {
+            log!(
+                "--Untested cells of {}: {}",
+                entry.mnemonic,
+                cells.join(" ")
+            );
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use core::arch::asm;
+use rand::Rng;
+use rvv_asm::rvv_asm;
+
+use ckb_std::syscalls::debug;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::intrinsic::{
+    clean_cache_v16, clean_cache_v24, clean_cache_v8, vle_v16, vle_v8, vs8r_v24, vse_v24, vsetvl,
+};
+use rvv_testcases::log;
+use rvv_testcases::misc::{is_full, is_verbose, VLEN};
+use rvv_testcases::rng::BestNumberRng;
+
+// larger than any VLMAX, vsetvl returns VLMAX
+const AVL_MAX: u64 = VLEN as u64 * 8;
+
+// Element i of a SEW-bit register group is stored at bytes
+// [i * SEW / 8, (i + 1) * SEW / 8), least significant byte first.
+fn expected_add(lhs: &[u8], rhs: &[u8], result: &mut [u8], sew_bytes: usize) {
+    for ((l, r), res) in lhs
+        .chunks(sew_bytes)
+        .zip(rhs.chunks(sew_bytes))
+        .zip(result.chunks_mut(sew_bytes))
+    {
+        let mut carry = 0u16;
+        for i in 0..sew_bytes {
+            let sum = l[i] as u16 + r[i] as u16 + carry;
+            res[i] = sum as u8;
+            carry = sum >> 8;
+        }
+    }
+}
+
+fn check(result: &[u8], expected: &[u8], desc: &str, sews: (u64, u64, u64), lmuls: (i64, i64)) {
+    if result != expected {
+        let index = result.iter().zip(expected.iter()).position(|(r, e)| r != e);
+        log!(
+            "[describe = {}] unexpected values found at byte {:?}, write: e{} lmul {}, op: e{} lmul {}, read: e{}",
+            desc,
+            index,
+            sews.0,
+            lmuls.0,
+            sews.1,
+            lmuls.1,
+            sews.2
+        );
+        log!("-expected: {:0>2X?}", expected);
+        log!("-result: {:0>2X?}", result);
+        panic!("Abort");
+    }
+}
+
+// v8/v16 are written under (write_sew, write_lmul), added under (op_sew,
+// op_lmul), then v24 is read back as a whole register image and with vse at
+// read_sew.
+fn run(write_sew: u64, write_lmul: i64, op_sew: u64, op_lmul: i64, read_sew: u64) {
+    let write_vl = vsetvl(AVL_MAX, write_sew, write_lmul);
+    if write_vl == 0 {
+        return;
+    }
+    let op_vl = vsetvl(AVL_MAX, op_sew, op_lmul);
+    if op_vl == 0 {
+        return;
+    }
+    if is_verbose() {
+        log!(
+            "run with write: e{} lmul {}, op: e{} lmul {}, read: e{}",
+            write_sew,
+            write_lmul,
+            op_sew,
+            op_lmul,
+            read_sew
+        );
+    }
+    let sews = (write_sew, op_sew, read_sew);
+    let lmuls = (write_lmul, op_lmul);
+
+    let write_len = (write_vl * write_sew / 8) as usize;
+    let op_len = (op_vl * op_sew / 8) as usize;
+
+    // bytes which are not written stay zero, see clean_cache_*
+    let mut rng = BestNumberRng::default();
+    let mut lhs = [0u8; VLEN];
+    let mut rhs = [0u8; VLEN];
+    rng.fill(&mut lhs[..write_len]);
+    rng.fill(&mut rhs[..write_len]);
+
+    let mut expected = [0u8; VLEN];
+    expected_add(
+        &lhs[..op_len],
+        &rhs[..op_len],
+        &mut expected[..op_len],
+        (op_sew / 8) as usize,
+    );
+
+    clean_cache_v8();
+    clean_cache_v16();
+    clean_cache_v24();
+
+    vsetvl(AVL_MAX, write_sew, write_lmul);
+    vle_v8(write_sew, &lhs[..write_len]);
+    vle_v16(write_sew, &rhs[..write_len]);
+
+    vsetvl(AVL_MAX, op_sew, op_lmul);
+    unsafe {
+        rvv_asm!("vadd.vv v24, v8, v16");
+    }
+    record_instruction("vadd.vv", op_sew, op_lmul, false);
+
+    let mut image = [0u8; VLEN];
+    vs8r_v24(&mut image);
+    check(&image, &expected, "vadd.vv, whole register", sews, lmuls);
+
+    // same group size, so the read covers exactly the bytes of the op
+    let read_vl = vsetvl(AVL_MAX, read_sew, op_lmul);
+    if read_vl != 0 {
+        let read_len = (read_vl * read_sew / 8) as usize;
+        assert_eq!(read_len, op_len);
+        let mut result = [0u8; VLEN];
+        vse_v24(read_sew, &mut result[..read_len]);
+        check(
+            &result[..read_len],
+            &expected[..read_len],
+            "vadd.vv, vse",
+            sews,
+            lmuls,
+        );
+    }
+}
+
+pub fn test_cross_sew() {
+    let sews = if is_full() {
+        [8, 16, 32, 64, 128, 256, 512, 1024].to_vec()
+    } else {
+        [8, 64, 256, 1024].to_vec()
+    };
+    let lmuls = if is_full() {
+        [-8, -4, -2, 1, 2, 4, 8].to_vec()
+    } else {
+        [-2, 1, 8].to_vec()
+    };
+
+    for write_sew in sews.clone() {
+        for op_sew in sews.clone() {
+            for read_sew in sews.clone() {
+                for write_lmul in lmuls.clone() {
+                    for op_lmul in lmuls.clone() {
+                        run(write_sew, write_lmul, op_sew, op_lmul, read_sew);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::format;
+use alloc::string::String;
+use blake2b_rs::{Blake2b, Blake2bBuilder};
+
+use super::misc::{get_avl_strategy, is_digest, is_full};
+use super::rng::get_seed;
+
+// With --digest, everything a case stores from the vector registers is hashed,
+// so runs on two ckb-vm releases can be diffed even when both pass. The
+// intrinsic stores feed it themselves, stores under test written with
+// `rvv_asm!` call `update_digest` on the memory they wrote.
+
+const DIGEST_LEN: usize = 32;
+
+// the whole case, and the runner configuration being run
+static mut CASE_HASHER: Option<Blake2b> = None;
+static mut CONFIG_HASHER: Option<Blake2b> = None;
+
+fn new_hasher() -> Option<Blake2b> {
+    if is_digest() {
+        Some(Blake2bBuilder::new(DIGEST_LEN).build())
+    } else {
+        None
+    }
+}
+
+fn to_hex(hasher: Option<Blake2b>) -> String {
+    let mut hash = [0u8; DIGEST_LEN];
+    if let Some(hasher) = hasher {
+        hasher.finalize(&mut hash);
+    }
+    let mut hex = String::new();
+    for b in hash.iter() {
+        hex.push_str(&format!("{:02x}", b));
+    }
+    hex
+}
+
+pub fn begin_digest() {
+    unsafe {
+        CASE_HASHER = new_hasher();
+    }
+}
+
+pub fn begin_config_digest() {
+    unsafe {
+        CONFIG_HASHER = new_hasher();
+    }
+}
+
+pub fn update_digest(data: &[u8]) {
+    unsafe {
+        if let Some(hasher) = CASE_HASHER.as_mut() {
+            hasher.update(data);
+        }
+        if let Some(hasher) = CONFIG_HASHER.as_mut() {
+            hasher.update(data);
+        }
+    }
+}
+
+// hex of the outputs since `begin_digest`, with the configuration that
+// produced them
+pub fn finish_digest() -> String {
+    format!(
+        "seed = {}, avl = {}, full = {}: {}",
+        get_seed(),
+        get_avl_strategy(),
+        is_full(),
+        to_hex(unsafe { CASE_HASHER.take() })
+    )
+}
+
+// hex of the outputs since `begin_config_digest`
+pub fn finish_config_digest() -> String {
+    to_hex(unsafe { CONFIG_HASHER.take() })
+}
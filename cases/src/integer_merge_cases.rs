@@ -2,6 +2,8 @@ use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E128, E256, E512};
 use rvv_asm::rvv_asm;
 use rvv_testcases::runner::{run_template_v_vim, run_template_v_vvm, run_template_v_vxm, MaskType};
+use rvv_testcases::rvv_asm_x;
+use rvv_testcases::rvv_op_i;
 
 fn test_vmerge_vvm() {
     fn exp_op(lhs: &[u8], rhs: &[u8], result: &mut [u8], mask: bool) {
@@ -41,7 +43,7 @@ fn test_vmerge_vxm() {
     fn op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vmerge.vxm v24, v8, t0, v0", in (reg) x);
+            rvv_asm_x!("vmerge.vxm v24, v8, t0, v0", x);
         }
     }
 
@@ -68,109 +70,7 @@ fn test_vmerge_vim() {
     }
     fn op(_: &[u8], rhs: &[u8], _: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => {
-                    rvv_asm!("vmerge.vim v24, v8, -16, v0");
-                }
-                -15 => {
-                    rvv_asm!("vmerge.vim v24, v8, -15, v0");
-                }
-                -14 => {
-                    rvv_asm!("vmerge.vim v24, v8, -14, v0");
-                }
-                -13 => {
-                    rvv_asm!("vmerge.vim v24, v8, -13, v0");
-                }
-                -12 => {
-                    rvv_asm!("vmerge.vim v24, v8, -12, v0");
-                }
-                -11 => {
-                    rvv_asm!("vmerge.vim v24, v8, -11, v0");
-                }
-                -10 => {
-                    rvv_asm!("vmerge.vim v24, v8, -10, v0");
-                }
-                -9 => {
-                    rvv_asm!("vmerge.vim v24, v8, -9, v0");
-                }
-                -8 => {
-                    rvv_asm!("vmerge.vim v24, v8, -8, v0");
-                }
-                -7 => {
-                    rvv_asm!("vmerge.vim v24, v8, -7, v0");
-                }
-                -6 => {
-                    rvv_asm!("vmerge.vim v24, v8, -6, v0");
-                }
-                -5 => {
-                    rvv_asm!("vmerge.vim v24, v8, -5, v0");
-                }
-                -4 => {
-                    rvv_asm!("vmerge.vim v24, v8, -4, v0");
-                }
-                -3 => {
-                    rvv_asm!("vmerge.vim v24, v8, -3, v0");
-                }
-                -2 => {
-                    rvv_asm!("vmerge.vim v24, v8, -2, v0");
-                }
-                -1 => {
-                    rvv_asm!("vmerge.vim v24, v8, -1, v0");
-                }
-                0 => {
-                    rvv_asm!("vmerge.vim v24, v8, 0, v0");
-                }
-                1 => {
-                    rvv_asm!("vmerge.vim v24, v8, 1, v0");
-                }
-                2 => {
-                    rvv_asm!("vmerge.vim v24, v8, 2, v0");
-                }
-                3 => {
-                    rvv_asm!("vmerge.vim v24, v8, 3, v0");
-                }
-                4 => {
-                    rvv_asm!("vmerge.vim v24, v8, 4, v0");
-                }
-                5 => {
-                    rvv_asm!("vmerge.vim v24, v8, 5, v0");
-                }
-                6 => {
-                    rvv_asm!("vmerge.vim v24, v8, 6, v0");
-                }
-                7 => {
-                    rvv_asm!("vmerge.vim v24, v8, 7, v0");
-                }
-                8 => {
-                    rvv_asm!("vmerge.vim v24, v8, 8, v0");
-                }
-                9 => {
-                    rvv_asm!("vmerge.vim v24, v8, 9, v0");
-                }
-                10 => {
-                    rvv_asm!("vmerge.vim v24, v8, 10, v0");
-                }
-                11 => {
-                    rvv_asm!("vmerge.vim v24, v8, 11, v0");
-                }
-                12 => {
-                    rvv_asm!("vmerge.vim v24, v8, 12, v0");
-                }
-                13 => {
-                    rvv_asm!("vmerge.vim v24, v8, 13, v0");
-                }
-                14 => {
-                    rvv_asm!("vmerge.vim v24, v8, 14, v0");
-                }
-                15 => {
-                    rvv_asm!("vmerge.vim v24, v8, 15, v0");
-                }
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vmerge.vim", imm, carry);
     }
 
     run_template_v_vim(exp_op, op, "vmerge.vim");
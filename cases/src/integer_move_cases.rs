@@ -3,7 +3,11 @@ use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E128, E256, E512};
 use rand::Rng;
 use rvv_asm::rvv_asm;
+use rvv_testcases::digest::update_digest;
+use rvv_testcases::rvv_asm_x;
+use rvv_testcases::rvv_op_i;
 use rvv_testcases::{
+    coverage::record_instruction,
     misc::VLEN,
     rng::BestNumberRng,
     runner::{run_template_v_vi, run_template_v_vv, run_template_v_vx, MaskType},
@@ -70,7 +74,7 @@ fn test_vmv_v_x() {
     fn op(_: &[u8], rhs: &[u8], _: MaskType) {
         let x = u64::from_le_bytes(rhs.try_into().unwrap());
         unsafe {
-            rvv_asm!("mv t0, {}", "vmv.v.x v24, t0", in (reg) x);
+            rvv_asm_x!("vmv.v.x v24, t0", x);
         }
     }
 
@@ -120,115 +124,15 @@ fn test_vmv_v_i() {
     }
     fn op(_: &[u8], rhs: &[u8], _: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => {
-                    rvv_asm!("vmv.v.i v24, -16");
-                }
-                -15 => {
-                    rvv_asm!("vmv.v.i v24, -15");
-                }
-                -14 => {
-                    rvv_asm!("vmv.v.i v24, -14");
-                }
-                -13 => {
-                    rvv_asm!("vmv.v.i v24, -13");
-                }
-                -12 => {
-                    rvv_asm!("vmv.v.i v24, -12");
-                }
-                -11 => {
-                    rvv_asm!("vmv.v.i v24, -11");
-                }
-                -10 => {
-                    rvv_asm!("vmv.v.i v24, -10");
-                }
-                -9 => {
-                    rvv_asm!("vmv.v.i v24, -9");
-                }
-                -8 => {
-                    rvv_asm!("vmv.v.i v24, -8");
-                }
-                -7 => {
-                    rvv_asm!("vmv.v.i v24, -7");
-                }
-                -6 => {
-                    rvv_asm!("vmv.v.i v24, -6");
-                }
-                -5 => {
-                    rvv_asm!("vmv.v.i v24, -5");
-                }
-                -4 => {
-                    rvv_asm!("vmv.v.i v24, -4");
-                }
-                -3 => {
-                    rvv_asm!("vmv.v.i v24, -3");
-                }
-                -2 => {
-                    rvv_asm!("vmv.v.i v24, -2");
-                }
-                -1 => {
-                    rvv_asm!("vmv.v.i v24, -1");
-                }
-                0 => {
-                    rvv_asm!("vmv.v.i v24, 0");
-                }
-                1 => {
-                    rvv_asm!("vmv.v.i v24, 1");
-                }
-                2 => {
-                    rvv_asm!("vmv.v.i v24, 2");
-                }
-                3 => {
-                    rvv_asm!("vmv.v.i v24, 3");
-                }
-                4 => {
-                    rvv_asm!("vmv.v.i v24, 4");
-                }
-                5 => {
-                    rvv_asm!("vmv.v.i v24, 5");
-                }
-                6 => {
-                    rvv_asm!("vmv.v.i v24, 6");
-                }
-                7 => {
-                    rvv_asm!("vmv.v.i v24, 7");
-                }
-                8 => {
-                    rvv_asm!("vmv.v.i v24, 8");
-                }
-                9 => {
-                    rvv_asm!("vmv.v.i v24, 9");
-                }
-                10 => {
-                    rvv_asm!("vmv.v.i v24, 10");
-                }
-                11 => {
-                    rvv_asm!("vmv.v.i v24, 11");
-                }
-                12 => {
-                    rvv_asm!("vmv.v.i v24, 12");
-                }
-                13 => {
-                    rvv_asm!("vmv.v.i v24, 13");
-                }
-                14 => {
-                    rvv_asm!("vmv.v.i v24, 14");
-                }
-                15 => {
-                    rvv_asm!("vmv.v.i v24, 15");
-                }
-                _ => {
-                    panic!("Abort, Val: {}", imm);
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vmv.v.i", imm, unmasked, vs2 = 0);
     }
 
     run_template_v_vi(expected_op, op, false, true, "vmv.v.i");
 }
 
 fn test_vmv1r_v_v() {
+    record_instruction("vmv1r.v", 8, 1, false);
+
     let mut rng = BestNumberRng::default();
 
     let mut buffer = Vec::<u8>::new();
@@ -263,6 +167,8 @@ fn test_vmv1r_v_v() {
             in (reg) result2.as_ptr(),
         );
     }
+    update_digest(&result);
+    update_digest(&result2);
 
     if buffer != result || buffer != result2 {
         log!("befor: {:0>2X?}, \nreslut: {:0>2X?}", buffer, result);
@@ -271,6 +177,8 @@ fn test_vmv1r_v_v() {
 }
 
 fn test_vmv2r_v_v() {
+    record_instruction("vmv2r.v", 8, 1, false);
+
     let mut rng = BestNumberRng::default();
 
     let mut buffer = Vec::<u8>::new();
@@ -310,6 +218,8 @@ fn test_vmv2r_v_v() {
             in (reg) result2[(VLEN/8)..].as_ptr()
         );
     }
+    update_digest(&result);
+    update_digest(&result2);
 
     if buffer != result || buffer != result2 {
         log!("befor: {:0>2X?}, reslut: {:0>2X?}", buffer, result);
@@ -318,6 +228,8 @@ fn test_vmv2r_v_v() {
 }
 
 fn test_vmv4r_v_v() {
+    record_instruction("vmv4r.v", 8, 1, false);
+
     let mut rng = BestNumberRng::default();
 
     let mut buffer = Vec::<u8>::new();
@@ -367,6 +279,8 @@ fn test_vmv4r_v_v() {
             in (reg) result2[(VLEN/8 * 3)..].as_ptr()
         );
     }
+    update_digest(&result);
+    update_digest(&result2);
 
     if buffer != result || buffer != result2 {
         log!("befor: {:0>2X?}, reslut: {:0>2X?}", buffer, result);
@@ -375,6 +289,8 @@ fn test_vmv4r_v_v() {
 }
 
 fn test_vmv8r_v_v() {
+    record_instruction("vmv8r.v", 8, 1, false);
+
     let mut rng = BestNumberRng::default();
 
     let mut buffer = Vec::<u8>::new();
@@ -444,6 +360,8 @@ fn test_vmv8r_v_v() {
             in (reg) result2[(VLEN/8 * 7)..].as_ptr()
         );
     }
+    update_digest(&result);
+    update_digest(&result2);
 
     if buffer != result || buffer != result2 {
         log!("befor: {:0>2X?}, reslut: {:0>2X?}", buffer, result);
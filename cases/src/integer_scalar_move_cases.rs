@@ -3,6 +3,7 @@ use rand::Rng;
 use rvv_asm::rvv_asm;
 
 use ckb_std::syscalls::debug;
+use rvv_testcases::coverage::record_instruction;
 use rvv_testcases::intrinsic::{vl1r_v8, vs1r_v8, vsetvl};
 use rvv_testcases::log;
 use rvv_testcases::misc::{is_verbose, VLEN};
@@ -19,6 +20,7 @@ fn vmv_x_s() {
 
     let vl = vsetvl(32, 64, 1) as usize;
     assert_eq!(vl, 32);
+    record_instruction("vmv.x.s", 64, 1, false);
 
     vl1r_v8(&vs2[..]);
 
@@ -68,6 +70,7 @@ fn vmv_s_x() {
 
     let vl = vsetvl(32, 64, 1) as usize;
     assert_eq!(vl, 32);
+    record_instruction("vmv.s.x", 64, 1, false);
 
     let mut vs2 = [0u8; VLEN / 8];
 
@@ -1,3 +1,4 @@
+use super::digest::update_digest;
 use super::intrinsic_setvl::{v_setivli, v_setvl, v_setvli};
 use super::misc::VLEN;
 use core::arch::asm;
@@ -13,6 +14,14 @@ pub fn get_rvv_len() -> u64 {
     unsafe { RVV_LEN }
 }
 
+// `vsetvl` rotates through vsetvl/vsetvli/vsetivli, restarting the rotation
+// makes a run reproducible
+pub fn reset_vsetvl_count() {
+    unsafe {
+        VSET_TEST_COUNT = 0;
+    }
+}
+
 pub fn vsetvl(avl: u64, sew: u64, lmul: i64) -> u64 {
     let count: u64;
     unsafe {
@@ -228,6 +237,7 @@ pub fn vse_v8(sew: u64, buf: &mut [u8]) {
             }
         }
     }
+    update_digest(buf);
 }
 
 pub fn vsse_v8(sew: u64, buf: &mut [u8], stride: u64) {
@@ -264,6 +274,7 @@ pub fn vsse_v8(sew: u64, buf: &mut [u8], stride: u64) {
             }
         }
     }
+    update_digest(buf);
 }
 
 pub fn vsuxei_v8(offset_sew: u64, buf: &mut [u8], offset: &[u8]) {
@@ -293,6 +304,7 @@ pub fn vsuxei_v8(offset_sew: u64, buf: &mut [u8], offset: &[u8]) {
             }
         }
     }
+    update_digest(buf);
 }
 
 pub fn vsoxei_v8(offset_sew: u64, buf: &mut [u8], offset: &[u8]) {
@@ -322,6 +334,7 @@ pub fn vsoxei_v8(offset_sew: u64, buf: &mut [u8], offset: &[u8]) {
             }
         }
     }
+    update_digest(buf);
 }
 
 pub fn vle_v16(sew: u64, buf: &[u8]) {
@@ -393,6 +406,7 @@ pub fn vse_v16(sew: u64, buf: &mut [u8]) {
             }
         }
     }
+    update_digest(buf);
 }
 
 pub fn vle_v24(sew: u64, buf: &[u8]) {
@@ -469,6 +483,7 @@ pub fn vse_v24(sew: u64, buf: &mut [u8]) {
             }
         }
     }
+    update_digest(buf);
 }
 
 pub fn vs1r_v8(buf: &mut [u8]) {
@@ -477,6 +492,7 @@ pub fn vs1r_v8(buf: &mut [u8]) {
     unsafe {
         rvv_asm!("mv t0, {}", "vs1r.v v8, (t0)", in (reg) p);
     }
+    update_digest(buf);
 }
 
 pub fn vs2r_v8(buf: &mut [u8]) {
@@ -485,6 +501,7 @@ pub fn vs2r_v8(buf: &mut [u8]) {
     unsafe {
         rvv_asm!("mv t0, {}", "vs2r.v v8, (t0)", in (reg) p);
     }
+    update_digest(buf);
 }
 
 pub fn vs4r_v8(buf: &mut [u8]) {
@@ -493,6 +510,7 @@ pub fn vs4r_v8(buf: &mut [u8]) {
     unsafe {
         rvv_asm!("mv t0, {}", "vs4r.v v8, (t0)", in (reg) p);
     }
+    update_digest(buf);
 }
 
 pub fn vs8r_v8(buf: &mut [u8]) {
@@ -501,6 +519,7 @@ pub fn vs8r_v8(buf: &mut [u8]) {
     unsafe {
         rvv_asm!("mv t0, {}", "vs8r.v v8, (t0)", in (reg) p);
     }
+    update_digest(buf);
 }
 
 pub fn vs1r_v24(buf: &mut [u8]) {
@@ -509,6 +528,16 @@ pub fn vs1r_v24(buf: &mut [u8]) {
     unsafe {
         rvv_asm!("mv t0, {}", "vs1r.v v24, (t0)", in (reg) p);
     }
+    update_digest(buf);
+}
+
+pub fn vs8r_v24(buf: &mut [u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vs8r.v v24, (t0)", in (reg) p);
+    }
+    update_digest(buf);
 }
 
 pub fn vl1r_v24(buf: &[u8]) {
@@ -527,6 +556,73 @@ pub fn vl1r_v8(buf: &[u8]) {
     }
 }
 
+pub fn vl8r_v8(buf: &[u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vl8re8.v v8, (t0)", in (reg) p);
+    }
+}
+
+pub fn vl8r_v16(buf: &[u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vl8re8.v v16, (t0)", in (reg) p);
+    }
+}
+
+pub fn vl8r_v24(buf: &[u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vl8re8.v v24, (t0)", in (reg) p);
+    }
+}
+
+// v0..v31, a group of 8 registers takes VLEN bytes
+pub fn vl8r_all(buf: &[u8]) {
+    assert!(buf.len() >= 4 * VLEN);
+    vl8r_v0(&buf[..VLEN]);
+    vl8r_v8(&buf[VLEN..2 * VLEN]);
+    vl8r_v16(&buf[2 * VLEN..3 * VLEN]);
+    vl8r_v24(&buf[3 * VLEN..4 * VLEN]);
+}
+
+pub fn vs8r_all(buf: &mut [u8]) {
+    assert!(buf.len() >= 4 * VLEN);
+    vs8r_v0(&mut buf[..VLEN]);
+    vs8r_v8(&mut buf[VLEN..2 * VLEN]);
+    vs8r_v16(&mut buf[2 * VLEN..3 * VLEN]);
+    vs8r_v24(&mut buf[3 * VLEN..4 * VLEN]);
+}
+
+pub fn vl8r_v0(buf: &[u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vl8re8.v v0, (t0)", in (reg) p);
+    }
+}
+
+pub fn vs8r_v0(buf: &mut [u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vs8r.v v0, (t0)", in (reg) p);
+    }
+    update_digest(buf);
+}
+
+pub fn vs8r_v16(buf: &mut [u8]) {
+    assert!(buf.len() >= VLEN);
+    let p = buf.as_ptr();
+    unsafe {
+        rvv_asm!("mv t0, {}", "vs8r.v v16, (t0)", in (reg) p);
+    }
+    update_digest(buf);
+}
+
 pub fn vl1r_v0(buf: &[u8]) {
     assert_eq!(buf.len(), VLEN / 8);
     let p = buf.as_ptr();
@@ -541,6 +637,7 @@ pub fn vsm_v_v8(buf: &mut [u8]) {
     unsafe {
         rvv_asm!("mv t0, {}", "vsm.v v8, (t0)", in (reg) p);
     }
+    update_digest(buf);
 }
 
 pub fn clean_cache_v8() {
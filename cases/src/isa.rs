@@ -0,0 +1,759 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+// RVV 1.0 instruction table, used to find out which instructions and which
+// configurations have not been tested yet (see `--coverage`).
+//
+// Memory instructions are listed without their EEW, e.g. `vle.v` stands for
+// vle8.v ... vle1024.v. `funct6` of memory instructions is nf/mew/mop.
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum OperandForm {
+    IVV,
+    IVX,
+    IVI,
+    MVV,
+    MVX,
+    Load,
+    Store,
+    Config,
+}
+
+pub struct Instruction {
+    pub mnemonic: &'static str,
+    pub funct6: u8,
+    pub form: OperandForm,
+    // has a `vm` bit
+    pub masked: bool,
+}
+
+const fn insn(mnemonic: &'static str, funct6: u8, form: OperandForm, masked: bool) -> Instruction {
+    Instruction {
+        mnemonic,
+        funct6,
+        form,
+        masked,
+    }
+}
+
+pub static INSTRUCTIONS: [Instruction; TABLE.len()] = TABLE;
+
+// `INSTRUCTIONS` for const fns, which can't read a static
+const TABLE: [Instruction; 318] = [
+    insn("vadd.vv", 0b000000, OperandForm::IVV, true),
+    insn("vadd.vx", 0b000000, OperandForm::IVX, true),
+    insn("vadd.vi", 0b000000, OperandForm::IVI, true),
+    insn("vsub.vv", 0b000010, OperandForm::IVV, true),
+    insn("vsub.vx", 0b000010, OperandForm::IVX, true),
+    insn("vrsub.vx", 0b000011, OperandForm::IVX, true),
+    insn("vrsub.vi", 0b000011, OperandForm::IVI, true),
+    insn("vminu.vv", 0b000100, OperandForm::IVV, true),
+    insn("vminu.vx", 0b000100, OperandForm::IVX, true),
+    insn("vmin.vv", 0b000101, OperandForm::IVV, true),
+    insn("vmin.vx", 0b000101, OperandForm::IVX, true),
+    insn("vmaxu.vv", 0b000110, OperandForm::IVV, true),
+    insn("vmaxu.vx", 0b000110, OperandForm::IVX, true),
+    insn("vmax.vv", 0b000111, OperandForm::IVV, true),
+    insn("vmax.vx", 0b000111, OperandForm::IVX, true),
+    insn("vand.vv", 0b001001, OperandForm::IVV, true),
+    insn("vand.vx", 0b001001, OperandForm::IVX, true),
+    insn("vand.vi", 0b001001, OperandForm::IVI, true),
+    insn("vor.vv", 0b001010, OperandForm::IVV, true),
+    insn("vor.vx", 0b001010, OperandForm::IVX, true),
+    insn("vor.vi", 0b001010, OperandForm::IVI, true),
+    insn("vxor.vv", 0b001011, OperandForm::IVV, true),
+    insn("vxor.vx", 0b001011, OperandForm::IVX, true),
+    insn("vxor.vi", 0b001011, OperandForm::IVI, true),
+    insn("vrgather.vv", 0b001100, OperandForm::IVV, true),
+    insn("vrgather.vx", 0b001100, OperandForm::IVX, true),
+    insn("vrgather.vi", 0b001100, OperandForm::IVI, true),
+    insn("vrgatherei16.vv", 0b001110, OperandForm::IVV, true),
+    insn("vslideup.vx", 0b001110, OperandForm::IVX, true),
+    insn("vslideup.vi", 0b001110, OperandForm::IVI, true),
+    insn("vslidedown.vx", 0b001111, OperandForm::IVX, true),
+    insn("vslidedown.vi", 0b001111, OperandForm::IVI, true),
+    insn("vadc.vvm", 0b010000, OperandForm::IVV, false),
+    insn("vadc.vxm", 0b010000, OperandForm::IVX, false),
+    insn("vadc.vim", 0b010000, OperandForm::IVI, false),
+    insn("vmadc.vvm", 0b010001, OperandForm::IVV, false),
+    insn("vmadc.vxm", 0b010001, OperandForm::IVX, false),
+    insn("vmadc.vim", 0b010001, OperandForm::IVI, false),
+    insn("vmadc.vv", 0b010001, OperandForm::IVV, false),
+    insn("vmadc.vx", 0b010001, OperandForm::IVX, false),
+    insn("vmadc.vi", 0b010001, OperandForm::IVI, false),
+    insn("vsbc.vvm", 0b010010, OperandForm::IVV, false),
+    insn("vsbc.vxm", 0b010010, OperandForm::IVX, false),
+    insn("vmsbc.vvm", 0b010011, OperandForm::IVV, false),
+    insn("vmsbc.vxm", 0b010011, OperandForm::IVX, false),
+    insn("vmsbc.vv", 0b010011, OperandForm::IVV, false),
+    insn("vmsbc.vx", 0b010011, OperandForm::IVX, false),
+    insn("vmerge.vvm", 0b010111, OperandForm::IVV, false),
+    insn("vmerge.vxm", 0b010111, OperandForm::IVX, false),
+    insn("vmerge.vim", 0b010111, OperandForm::IVI, false),
+    insn("vmseq.vv", 0b011000, OperandForm::IVV, true),
+    insn("vmseq.vx", 0b011000, OperandForm::IVX, true),
+    insn("vmseq.vi", 0b011000, OperandForm::IVI, true),
+    insn("vmsne.vv", 0b011001, OperandForm::IVV, true),
+    insn("vmsne.vx", 0b011001, OperandForm::IVX, true),
+    insn("vmsne.vi", 0b011001, OperandForm::IVI, true),
+    insn("vmsltu.vv", 0b011010, OperandForm::IVV, true),
+    insn("vmsltu.vx", 0b011010, OperandForm::IVX, true),
+    insn("vmslt.vv", 0b011011, OperandForm::IVV, true),
+    insn("vmslt.vx", 0b011011, OperandForm::IVX, true),
+    insn("vmsleu.vv", 0b011100, OperandForm::IVV, true),
+    insn("vmsleu.vx", 0b011100, OperandForm::IVX, true),
+    insn("vmsleu.vi", 0b011100, OperandForm::IVI, true),
+    insn("vmsle.vv", 0b011101, OperandForm::IVV, true),
+    insn("vmsle.vx", 0b011101, OperandForm::IVX, true),
+    insn("vmsle.vi", 0b011101, OperandForm::IVI, true),
+    insn("vmsgtu.vx", 0b011110, OperandForm::IVX, true),
+    insn("vmsgtu.vi", 0b011110, OperandForm::IVI, true),
+    insn("vmsgt.vx", 0b011111, OperandForm::IVX, true),
+    insn("vmsgt.vi", 0b011111, OperandForm::IVI, true),
+    insn("vsaddu.vv", 0b100000, OperandForm::IVV, true),
+    insn("vsaddu.vx", 0b100000, OperandForm::IVX, true),
+    insn("vsaddu.vi", 0b100000, OperandForm::IVI, true),
+    insn("vsadd.vv", 0b100001, OperandForm::IVV, true),
+    insn("vsadd.vx", 0b100001, OperandForm::IVX, true),
+    insn("vsadd.vi", 0b100001, OperandForm::IVI, true),
+    insn("vssubu.vv", 0b100010, OperandForm::IVV, true),
+    insn("vssubu.vx", 0b100010, OperandForm::IVX, true),
+    insn("vssub.vv", 0b100011, OperandForm::IVV, true),
+    insn("vssub.vx", 0b100011, OperandForm::IVX, true),
+    insn("vsll.vv", 0b100101, OperandForm::IVV, true),
+    insn("vsll.vx", 0b100101, OperandForm::IVX, true),
+    insn("vsll.vi", 0b100101, OperandForm::IVI, true),
+    insn("vsmul.vv", 0b100111, OperandForm::IVV, true),
+    insn("vsmul.vx", 0b100111, OperandForm::IVX, true),
+    insn("vsrl.vv", 0b101000, OperandForm::IVV, true),
+    insn("vsrl.vx", 0b101000, OperandForm::IVX, true),
+    insn("vsrl.vi", 0b101000, OperandForm::IVI, true),
+    insn("vsra.vv", 0b101001, OperandForm::IVV, true),
+    insn("vsra.vx", 0b101001, OperandForm::IVX, true),
+    insn("vsra.vi", 0b101001, OperandForm::IVI, true),
+    insn("vssrl.vv", 0b101010, OperandForm::IVV, true),
+    insn("vssrl.vx", 0b101010, OperandForm::IVX, true),
+    insn("vssrl.vi", 0b101010, OperandForm::IVI, true),
+    insn("vssra.vv", 0b101011, OperandForm::IVV, true),
+    insn("vssra.vx", 0b101011, OperandForm::IVX, true),
+    insn("vssra.vi", 0b101011, OperandForm::IVI, true),
+    insn("vnsrl.wv", 0b101100, OperandForm::IVV, true),
+    insn("vnsrl.wx", 0b101100, OperandForm::IVX, true),
+    insn("vnsrl.wi", 0b101100, OperandForm::IVI, true),
+    insn("vnsra.wv", 0b101101, OperandForm::IVV, true),
+    insn("vnsra.wx", 0b101101, OperandForm::IVX, true),
+    insn("vnsra.wi", 0b101101, OperandForm::IVI, true),
+    insn("vnclipu.wv", 0b101110, OperandForm::IVV, true),
+    insn("vnclipu.wx", 0b101110, OperandForm::IVX, true),
+    insn("vnclipu.wi", 0b101110, OperandForm::IVI, true),
+    insn("vnclip.wv", 0b101111, OperandForm::IVV, true),
+    insn("vnclip.wx", 0b101111, OperandForm::IVX, true),
+    insn("vnclip.wi", 0b101111, OperandForm::IVI, true),
+    insn("vwredsumu.vs", 0b110000, OperandForm::IVV, true),
+    insn("vwredsum.vs", 0b110001, OperandForm::IVV, true),
+    insn("vmv.v.v", 0b010111, OperandForm::IVV, false),
+    insn("vmv.v.x", 0b010111, OperandForm::IVX, false),
+    insn("vmv.v.i", 0b010111, OperandForm::IVI, false),
+    insn("vmv1r.v", 0b100111, OperandForm::IVI, false),
+    insn("vmv2r.v", 0b100111, OperandForm::IVI, false),
+    insn("vmv4r.v", 0b100111, OperandForm::IVI, false),
+    insn("vmv8r.v", 0b100111, OperandForm::IVI, false),
+    insn("vredsum.vs", 0b000000, OperandForm::MVV, true),
+    insn("vredand.vs", 0b000001, OperandForm::MVV, true),
+    insn("vredor.vs", 0b000010, OperandForm::MVV, true),
+    insn("vredxor.vs", 0b000011, OperandForm::MVV, true),
+    insn("vredminu.vs", 0b000100, OperandForm::MVV, true),
+    insn("vredmin.vs", 0b000101, OperandForm::MVV, true),
+    insn("vredmaxu.vs", 0b000110, OperandForm::MVV, true),
+    insn("vredmax.vs", 0b000111, OperandForm::MVV, true),
+    insn("vaaddu.vv", 0b001000, OperandForm::MVV, true),
+    insn("vaaddu.vx", 0b001000, OperandForm::MVX, true),
+    insn("vaadd.vv", 0b001001, OperandForm::MVV, true),
+    insn("vaadd.vx", 0b001001, OperandForm::MVX, true),
+    insn("vasubu.vv", 0b001010, OperandForm::MVV, true),
+    insn("vasubu.vx", 0b001010, OperandForm::MVX, true),
+    insn("vasub.vv", 0b001011, OperandForm::MVV, true),
+    insn("vasub.vx", 0b001011, OperandForm::MVX, true),
+    insn("vslide1up.vx", 0b001110, OperandForm::MVX, true),
+    insn("vslide1down.vx", 0b001111, OperandForm::MVX, true),
+    insn("vmv.x.s", 0b010000, OperandForm::MVV, false),
+    insn("vcpop.m", 0b010000, OperandForm::MVV, true),
+    insn("vfirst.m", 0b010000, OperandForm::MVV, true),
+    insn("vmv.s.x", 0b010000, OperandForm::MVX, false),
+    insn("vzext.vf8", 0b010010, OperandForm::MVV, true),
+    insn("vsext.vf8", 0b010010, OperandForm::MVV, true),
+    insn("vzext.vf4", 0b010010, OperandForm::MVV, true),
+    insn("vsext.vf4", 0b010010, OperandForm::MVV, true),
+    insn("vzext.vf2", 0b010010, OperandForm::MVV, true),
+    insn("vsext.vf2", 0b010010, OperandForm::MVV, true),
+    insn("vmsbf.m", 0b010100, OperandForm::MVV, true),
+    insn("vmsof.m", 0b010100, OperandForm::MVV, true),
+    insn("vmsif.m", 0b010100, OperandForm::MVV, true),
+    insn("viota.m", 0b010100, OperandForm::MVV, true),
+    insn("vid.v", 0b010100, OperandForm::MVV, true),
+    insn("vcompress.vm", 0b010111, OperandForm::MVV, false),
+    insn("vmandn.mm", 0b011000, OperandForm::MVV, false),
+    insn("vmand.mm", 0b011001, OperandForm::MVV, false),
+    insn("vmor.mm", 0b011010, OperandForm::MVV, false),
+    insn("vmxor.mm", 0b011011, OperandForm::MVV, false),
+    insn("vmorn.mm", 0b011100, OperandForm::MVV, false),
+    insn("vmnand.mm", 0b011101, OperandForm::MVV, false),
+    insn("vmnor.mm", 0b011110, OperandForm::MVV, false),
+    insn("vmxnor.mm", 0b011111, OperandForm::MVV, false),
+    insn("vdivu.vv", 0b100000, OperandForm::MVV, true),
+    insn("vdivu.vx", 0b100000, OperandForm::MVX, true),
+    insn("vdiv.vv", 0b100001, OperandForm::MVV, true),
+    insn("vdiv.vx", 0b100001, OperandForm::MVX, true),
+    insn("vremu.vv", 0b100010, OperandForm::MVV, true),
+    insn("vremu.vx", 0b100010, OperandForm::MVX, true),
+    insn("vrem.vv", 0b100011, OperandForm::MVV, true),
+    insn("vrem.vx", 0b100011, OperandForm::MVX, true),
+    insn("vmulhu.vv", 0b100100, OperandForm::MVV, true),
+    insn("vmulhu.vx", 0b100100, OperandForm::MVX, true),
+    insn("vmul.vv", 0b100101, OperandForm::MVV, true),
+    insn("vmul.vx", 0b100101, OperandForm::MVX, true),
+    insn("vmulhsu.vv", 0b100110, OperandForm::MVV, true),
+    insn("vmulhsu.vx", 0b100110, OperandForm::MVX, true),
+    insn("vmulh.vv", 0b100111, OperandForm::MVV, true),
+    insn("vmulh.vx", 0b100111, OperandForm::MVX, true),
+    insn("vmadd.vv", 0b101001, OperandForm::MVV, true),
+    insn("vmadd.vx", 0b101001, OperandForm::MVX, true),
+    insn("vnmsub.vv", 0b101011, OperandForm::MVV, true),
+    insn("vnmsub.vx", 0b101011, OperandForm::MVX, true),
+    insn("vmacc.vv", 0b101101, OperandForm::MVV, true),
+    insn("vmacc.vx", 0b101101, OperandForm::MVX, true),
+    insn("vnmsac.vv", 0b101111, OperandForm::MVV, true),
+    insn("vnmsac.vx", 0b101111, OperandForm::MVX, true),
+    insn("vwaddu.vv", 0b110000, OperandForm::MVV, true),
+    insn("vwaddu.vx", 0b110000, OperandForm::MVX, true),
+    insn("vwadd.vv", 0b110001, OperandForm::MVV, true),
+    insn("vwadd.vx", 0b110001, OperandForm::MVX, true),
+    insn("vwsubu.vv", 0b110010, OperandForm::MVV, true),
+    insn("vwsubu.vx", 0b110010, OperandForm::MVX, true),
+    insn("vwsub.vv", 0b110011, OperandForm::MVV, true),
+    insn("vwsub.vx", 0b110011, OperandForm::MVX, true),
+    insn("vwaddu.wv", 0b110100, OperandForm::MVV, true),
+    insn("vwaddu.wx", 0b110100, OperandForm::MVX, true),
+    insn("vwadd.wv", 0b110101, OperandForm::MVV, true),
+    insn("vwadd.wx", 0b110101, OperandForm::MVX, true),
+    insn("vwsubu.wv", 0b110110, OperandForm::MVV, true),
+    insn("vwsubu.wx", 0b110110, OperandForm::MVX, true),
+    insn("vwsub.wv", 0b110111, OperandForm::MVV, true),
+    insn("vwsub.wx", 0b110111, OperandForm::MVX, true),
+    insn("vwmulu.vv", 0b111000, OperandForm::MVV, true),
+    insn("vwmulu.vx", 0b111000, OperandForm::MVX, true),
+    insn("vwmulsu.vv", 0b111010, OperandForm::MVV, true),
+    insn("vwmulsu.vx", 0b111010, OperandForm::MVX, true),
+    insn("vwmul.vv", 0b111011, OperandForm::MVV, true),
+    insn("vwmul.vx", 0b111011, OperandForm::MVX, true),
+    insn("vwmaccu.vv", 0b111100, OperandForm::MVV, true),
+    insn("vwmaccu.vx", 0b111100, OperandForm::MVX, true),
+    insn("vwmacc.vv", 0b111101, OperandForm::MVV, true),
+    insn("vwmacc.vx", 0b111101, OperandForm::MVX, true),
+    insn("vwmaccus.vx", 0b111110, OperandForm::MVX, true),
+    insn("vwmaccsu.vv", 0b111111, OperandForm::MVV, true),
+    insn("vwmaccsu.vx", 0b111111, OperandForm::MVX, true),
+    insn("vle.v", 0b000000, OperandForm::Load, true),
+    insn("vse.v", 0b000000, OperandForm::Store, true),
+    insn("vleff.v", 0b000000, OperandForm::Load, true),
+    insn("vlm.v", 0b000000, OperandForm::Load, false),
+    insn("vsm.v", 0b000000, OperandForm::Store, false),
+    insn("vlse.v", 0b000010, OperandForm::Load, true),
+    insn("vsse.v", 0b000010, OperandForm::Store, true),
+    insn("vluxei.v", 0b000001, OperandForm::Load, true),
+    insn("vloxei.v", 0b000011, OperandForm::Load, true),
+    insn("vsuxei.v", 0b000001, OperandForm::Store, true),
+    insn("vsoxei.v", 0b000011, OperandForm::Store, true),
+    insn("vlseg2e.v", 0b001000, OperandForm::Load, true),
+    insn("vsseg2e.v", 0b001000, OperandForm::Store, true),
+    insn("vlseg2eff.v", 0b001000, OperandForm::Load, true),
+    insn("vlsseg2e.v", 0b001010, OperandForm::Load, true),
+    insn("vssseg2e.v", 0b001010, OperandForm::Store, true),
+    insn("vluxseg2ei.v", 0b001001, OperandForm::Load, true),
+    insn("vloxseg2ei.v", 0b001011, OperandForm::Load, true),
+    insn("vsuxseg2ei.v", 0b001001, OperandForm::Store, true),
+    insn("vsoxseg2ei.v", 0b001011, OperandForm::Store, true),
+    insn("vlseg3e.v", 0b010000, OperandForm::Load, true),
+    insn("vsseg3e.v", 0b010000, OperandForm::Store, true),
+    insn("vlseg3eff.v", 0b010000, OperandForm::Load, true),
+    insn("vlsseg3e.v", 0b010010, OperandForm::Load, true),
+    insn("vssseg3e.v", 0b010010, OperandForm::Store, true),
+    insn("vluxseg3ei.v", 0b010001, OperandForm::Load, true),
+    insn("vloxseg3ei.v", 0b010011, OperandForm::Load, true),
+    insn("vsuxseg3ei.v", 0b010001, OperandForm::Store, true),
+    insn("vsoxseg3ei.v", 0b010011, OperandForm::Store, true),
+    insn("vlseg4e.v", 0b011000, OperandForm::Load, true),
+    insn("vsseg4e.v", 0b011000, OperandForm::Store, true),
+    insn("vlseg4eff.v", 0b011000, OperandForm::Load, true),
+    insn("vlsseg4e.v", 0b011010, OperandForm::Load, true),
+    insn("vssseg4e.v", 0b011010, OperandForm::Store, true),
+    insn("vluxseg4ei.v", 0b011001, OperandForm::Load, true),
+    insn("vloxseg4ei.v", 0b011011, OperandForm::Load, true),
+    insn("vsuxseg4ei.v", 0b011001, OperandForm::Store, true),
+    insn("vsoxseg4ei.v", 0b011011, OperandForm::Store, true),
+    insn("vlseg5e.v", 0b100000, OperandForm::Load, true),
+    insn("vsseg5e.v", 0b100000, OperandForm::Store, true),
+    insn("vlseg5eff.v", 0b100000, OperandForm::Load, true),
+    insn("vlsseg5e.v", 0b100010, OperandForm::Load, true),
+    insn("vssseg5e.v", 0b100010, OperandForm::Store, true),
+    insn("vluxseg5ei.v", 0b100001, OperandForm::Load, true),
+    insn("vloxseg5ei.v", 0b100011, OperandForm::Load, true),
+    insn("vsuxseg5ei.v", 0b100001, OperandForm::Store, true),
+    insn("vsoxseg5ei.v", 0b100011, OperandForm::Store, true),
+    insn("vlseg6e.v", 0b101000, OperandForm::Load, true),
+    insn("vsseg6e.v", 0b101000, OperandForm::Store, true),
+    insn("vlseg6eff.v", 0b101000, OperandForm::Load, true),
+    insn("vlsseg6e.v", 0b101010, OperandForm::Load, true),
+    insn("vssseg6e.v", 0b101010, OperandForm::Store, true),
+    insn("vluxseg6ei.v", 0b101001, OperandForm::Load, true),
+    insn("vloxseg6ei.v", 0b101011, OperandForm::Load, true),
+    insn("vsuxseg6ei.v", 0b101001, OperandForm::Store, true),
+    insn("vsoxseg6ei.v", 0b101011, OperandForm::Store, true),
+    insn("vlseg7e.v", 0b110000, OperandForm::Load, true),
+    insn("vsseg7e.v", 0b110000, OperandForm::Store, true),
+    insn("vlseg7eff.v", 0b110000, OperandForm::Load, true),
+    insn("vlsseg7e.v", 0b110010, OperandForm::Load, true),
+    insn("vssseg7e.v", 0b110010, OperandForm::Store, true),
+    insn("vluxseg7ei.v", 0b110001, OperandForm::Load, true),
+    insn("vloxseg7ei.v", 0b110011, OperandForm::Load, true),
+    insn("vsuxseg7ei.v", 0b110001, OperandForm::Store, true),
+    insn("vsoxseg7ei.v", 0b110011, OperandForm::Store, true),
+    insn("vlseg8e.v", 0b111000, OperandForm::Load, true),
+    insn("vsseg8e.v", 0b111000, OperandForm::Store, true),
+    insn("vlseg8eff.v", 0b111000, OperandForm::Load, true),
+    insn("vlsseg8e.v", 0b111010, OperandForm::Load, true),
+    insn("vssseg8e.v", 0b111010, OperandForm::Store, true),
+    insn("vluxseg8ei.v", 0b111001, OperandForm::Load, true),
+    insn("vloxseg8ei.v", 0b111011, OperandForm::Load, true),
+    insn("vsuxseg8ei.v", 0b111001, OperandForm::Store, true),
+    insn("vsoxseg8ei.v", 0b111011, OperandForm::Store, true),
+    insn("vl1re.v", 0b000000, OperandForm::Load, false),
+    insn("vl2re.v", 0b001000, OperandForm::Load, false),
+    insn("vl4re.v", 0b011000, OperandForm::Load, false),
+    insn("vl8re.v", 0b111000, OperandForm::Load, false),
+    insn("vs1r.v", 0b000000, OperandForm::Store, false),
+    insn("vs2r.v", 0b001000, OperandForm::Store, false),
+    insn("vs4r.v", 0b011000, OperandForm::Store, false),
+    insn("vs8r.v", 0b111000, OperandForm::Store, false),
+    insn("vsetvli", 0b000000, OperandForm::Config, false),
+    insn("vsetivli", 0b000000, OperandForm::Config, false),
+    insn("vsetvl", 0b000000, OperandForm::Config, false),
+    // Zvbb, the unary ones are told apart by vs1
+    insn("vandn.vv", 0b000001, OperandForm::IVV, true),
+    insn("vandn.vx", 0b000001, OperandForm::IVX, true),
+    insn("vbrev.v", 0b010010, OperandForm::MVV, true),
+    insn("vbrev8.v", 0b010010, OperandForm::MVV, true),
+    insn("vrev8.v", 0b010010, OperandForm::MVV, true),
+    insn("vclz.v", 0b010010, OperandForm::MVV, true),
+    insn("vctz.v", 0b010010, OperandForm::MVV, true),
+    insn("vcpop.v", 0b010010, OperandForm::MVV, true),
+    insn("vrol.vv", 0b010101, OperandForm::IVV, true),
+    insn("vrol.vx", 0b010101, OperandForm::IVX, true),
+    insn("vror.vv", 0b010100, OperandForm::IVV, true),
+    insn("vror.vx", 0b010100, OperandForm::IVX, true),
+    insn("vror.vi", 0b010100, OperandForm::IVI, true),
+    insn("vwsll.vv", 0b110101, OperandForm::IVV, true),
+    insn("vwsll.vx", 0b110101, OperandForm::IVX, true),
+    insn("vwsll.vi", 0b110101, OperandForm::IVI, true),
+    // Zvbc
+    insn("vclmul.vv", 0b001100, OperandForm::MVV, true),
+    insn("vclmul.vx", 0b001100, OperandForm::MVX, true),
+    insn("vclmulh.vv", 0b001101, OperandForm::MVV, true),
+    insn("vclmulh.vx", 0b001101, OperandForm::MVX, true),
+];
+
+// Machine code of an OP-V arithmetic instruction, for the instructions which
+// rvv_asm can't assemble (see `raw_op!`). `vs1` is vs1, rs1 or the 5-bit immediate, depending
+// on `form`.
+pub const fn encode(
+    funct6: u8,
+    form: OperandForm,
+    masked: bool,
+    vd: u32,
+    vs2: u32,
+    vs1: u32,
+) -> u32 {
+    let funct3 = match form {
+        OperandForm::IVV => 0b000,
+        OperandForm::MVV => 0b010,
+        OperandForm::IVI => 0b011,
+        OperandForm::IVX => 0b100,
+        OperandForm::MVX => 0b110,
+        _ => panic!("not an arithmetic instruction"),
+    };
+    let vm = if masked { 0 } else { 1 };
+    ((funct6 as u32) << 26)
+        | (vm << 25)
+        | (vs2 << 20)
+        | ((vs1 & 0x1F) << 15)
+        | (funct3 << 12)
+        | (vd << 7)
+        | 0x57
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+// funct6 and operand form of `mnemonic`, at compile time (see `rvv_insns!`)
+pub const fn lookup(mnemonic: &str) -> (u8, OperandForm) {
+    let mut i = 0;
+    while i < TABLE.len() {
+        if str_eq(TABLE[i].mnemonic, mnemonic) {
+            return (TABLE[i].funct6, TABLE[i].form);
+        }
+        i += 1;
+    }
+    panic!("unknown instruction");
+}
+
+// Old names still accepted by the assembler
+const ALIASES: [(&str, &str); 2] = [("vmandnot.mm", "vmandn.mm"), ("vmornot.mm", "vmorn.mm")];
+
+pub fn find_instruction(mnemonic: &str) -> Option<&'static Instruction> {
+    INSTRUCTIONS.iter().find(|i| i.mnemonic == mnemonic)
+}
+
+// The EEW encoded in a memory mnemonic, e.g. 64 for vle64.v
+pub fn memory_eew(mnemonic: &str) -> Option<u64> {
+    let (stem, _) = split_memory_mnemonic(mnemonic)?;
+    let digits = stem.len() - stem.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    if digits == 0 {
+        return None;
+    }
+    stem[stem.len() - digits..].parse::<u64>().ok()
+}
+
+// Map a mnemonic to its name in `INSTRUCTIONS`:
+// vle64.v => vle.v, vluxei16.v => vluxei.v, vlseg2e8ff.v => vlseg2eff.v,
+// vl2re32.v => vl2re.v, vmandnot.mm => vmandn.mm
+pub fn canonical_mnemonic(mnemonic: &str) -> String {
+    for &(alias, name) in ALIASES.iter() {
+        if mnemonic == alias {
+            return name.to_string();
+        }
+    }
+    match split_memory_mnemonic(mnemonic) {
+        Some((stem, ff)) => {
+            let stem = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+            format!("{}{}.v", stem, if ff { "ff" } else { "" })
+        }
+        None => mnemonic.to_string(),
+    }
+}
+
+// "vle64ff.v" => ("vle64", true)
+fn split_memory_mnemonic(mnemonic: &str) -> Option<(&str, bool)> {
+    if !(mnemonic.starts_with("vl") || mnemonic.starts_with("vs")) {
+        return None;
+    }
+    let stem = mnemonic.strip_suffix(".v")?;
+    match stem.strip_suffix("ff") {
+        Some(stem) => Some((stem, true)),
+        None => Some((stem, false)),
+    }
+}
+
+// `op v24, v8, v16` (`vs1` = 16), or `op v24, v8, vs1` for the unary ones
+#[macro_export]
+macro_rules! raw_op {
+    ($funct6:expr, $form:expr, $vs1:expr, $mask_type:expr) => {
+        unsafe {
+            match $mask_type {
+                $crate::runner::MaskType::Enable => {
+                    ::core::arch::asm!(
+                        ".word {}",
+                        const $crate::isa::encode($funct6, $form, true, 24, 8, $vs1)
+                    );
+                }
+                $crate::runner::MaskType::Disable => {
+                    ::core::arch::asm!(
+                        ".word {}",
+                        const $crate::isa::encode($funct6, $form, false, 24, 8, $vs1)
+                    );
+                }
+                _ => panic!("Abort"),
+            }
+        }
+    };
+}
+
+// `op v24, v8, t0`, with t0 = x. t0 is read back and compared with x.
+#[macro_export]
+macro_rules! raw_op_x {
+    ($funct6:expr, $form:expr, $x:expr, $mask_type:expr) => {{
+        let x: u64 = $x;
+        let after: u64;
+        unsafe {
+            match $mask_type {
+                $crate::runner::MaskType::Enable => {
+                    ::core::arch::asm!(
+                        "mv t0, {}",
+                        ".word {}",
+                        "mv {}, t0",
+                        in (reg) x,
+                        const $crate::isa::encode($funct6, $form, true, 24, 8, 5),
+                        out (reg) after,
+                        out ("t0") _,
+                    );
+                }
+                $crate::runner::MaskType::Disable => {
+                    ::core::arch::asm!(
+                        "mv t0, {}",
+                        ".word {}",
+                        "mv {}, t0",
+                        in (reg) x,
+                        const $crate::isa::encode($funct6, $form, false, 24, 8, 5),
+                        out (reg) after,
+                        out ("t0") _,
+                    );
+                }
+                _ => panic!("Abort"),
+            }
+        }
+        $crate::runner::check_scalar_operand(x, after);
+    }};
+}
+
+// `rvv_asm!` of an op reading its scalar operand from t0, with t0 = x. t0 is
+// read back and compared with x. Like `rvv_asm!` it must be used in an unsafe
+// block.
+#[macro_export]
+macro_rules! rvv_asm_x {
+    ($insn:tt, $x:expr) => {{
+        let x: u64 = $x;
+        let after: u64;
+        ::rvv_asm::rvv_asm!(
+            "mv t0, {}",
+            $insn,
+            "mv {}, t0",
+            in (reg) x,
+            out (reg) after,
+            out ("t0") _,
+        );
+        $crate::runner::check_scalar_operand(x, after);
+    }};
+}
+
+// `op v24, v8, imm`. The immediate must be a constant, so there is one arm per
+// value in the list. Bit 5 of the immediate goes to funct6, like vror.vi.
+#[macro_export]
+macro_rules! raw_op_i {
+    ($funct6:expr, $imm:expr, $mask_type:expr, [$($i:literal),*]) => {
+        match $imm {
+            $($i => $crate::raw_op!(
+                $funct6 | ($i >> 5),
+                $crate::isa::OperandForm::IVI,
+                $i,
+                $mask_type
+            ),)*
+            _ => panic!("Abort"),
+        }
+    };
+}
+
+// `match` on the immediate of `name v24, v8, imm`, one arm per simm5 or uimm5,
+// encoded from `INSTRUCTIONS`:
+//
+//     rvv_op_i!(simm5, "vmseq.vi", imm, mask_type);
+//     rvv_op_i!(simm5, "vadc.vim", imm, carry);
+//     rvv_op_i!(uimm5, "vrgather.vi", imm, unmasked);
+//     rvv_op_i!(simm5, "vmv.v.i", imm, unmasked, vs2 = 0);
+//
+// `carry` always reads v0 like the .vim forms, `unmasked` never does, any other
+// expression is the `MaskType`.
+#[macro_export]
+macro_rules! rvv_op_i {
+    (simm5, $($rest:tt)*) => {
+        $crate::rvv_op_i!(@arms [
+            -16, -15, -14, -13, -12, -11, -10, -9, -8, -7, -6, -5, -4, -3, -2, -1,
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15
+        ], $($rest)*)
+    };
+    (uimm5, $($rest:tt)*) => {
+        $crate::rvv_op_i!(@arms [
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+            16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31
+        ], $($rest)*)
+    };
+    (@arms [$($i:literal),*], $name:expr, $imm:expr, $vm:tt) => {
+        $crate::rvv_op_i!(@arms [$($i),*], $name, $imm, $vm, vs2 = 8)
+    };
+    (@arms [$($i:literal),*], $name:expr, $imm:expr, $vm:tt, vs2 = $vs2:tt) => {{
+        const INSN: (u8, $crate::isa::OperandForm) = $crate::isa::lookup($name);
+        match $imm {
+            $($i => $crate::rvv_op_i!(@op INSN, $i as i32 as u32, $vm, $vs2),)*
+            _ => panic!("Abort"),
+        }
+    }};
+    (@op $insn:ident, $imm:expr, carry, $vs2:tt) => {
+        unsafe {
+            ::core::arch::asm!(
+                ".word {}",
+                const $crate::isa::encode($insn.0, $insn.1, true, 24, $vs2, $imm)
+            );
+        }
+    };
+    (@op $insn:ident, $imm:expr, unmasked, $vs2:tt) => {
+        unsafe {
+            ::core::arch::asm!(
+                ".word {}",
+                const $crate::isa::encode($insn.0, $insn.1, false, 24, $vs2, $imm)
+            );
+        }
+    };
+    (@op $insn:ident, $imm:expr, $mask_type:expr, 8) => {
+        $crate::raw_op!($insn.0, $insn.1, $imm, $mask_type)
+    };
+}
+
+// Declares a function registering every listed instruction as a test case,
+// masked and unmasked if `masked`:
+//
+//     rvv_insns! {
+//         pub fn test_vop;
+//         vadd, vv | vx | vi, masked, ref = Add;
+//         vdivu, vv | vxu, masked, ref = Divu;
+//         vmseq, mvi, masked, exp = expected_eq_vi;
+//     }
+//
+// and `test_vop(test_pattern)` is called from `run_cases`. Each form is its own
+// case named after the module and the instruction, e.g. `vop_cases::vadd.vx`.
+//
+// `ref` is a `BinaryOp`, `exp` a reference function of the form's template.
+// `vx` sign-extends the scalar to SEW and `vxu` zero-extends it. `vi` takes a
+// simm5 and `viu` a uimm5, both are tested with every value.
+// `mvv`, `mvx` and `mvi` write a mask (`run_template_m_*`). The encoding comes
+// from `INSTRUCTIONS` so a new instruction only needs a table entry, a
+// reference and one line here.
+#[macro_export]
+macro_rules! rvv_insns {
+    (@op $name:ident, vv) => {
+        fn op(_: &[u8], _: &[u8], mask_type: $crate::runner::MaskType) {
+            const INSN: (u8, $crate::isa::OperandForm) =
+                $crate::isa::lookup(concat!(stringify!($name), ".vv"));
+            $crate::raw_op!(INSN.0, INSN.1, 16, mask_type);
+        }
+    };
+    (@op $name:ident, vx) => {
+        fn op(_: &[u8], rhs: &[u8], mask_type: $crate::runner::MaskType) {
+            const INSN: (u8, $crate::isa::OperandForm) =
+                $crate::isa::lookup(concat!(stringify!($name), ".vx"));
+            let x = u64::from_le_bytes(::core::convert::TryInto::try_into(rhs).unwrap());
+            $crate::raw_op_x!(INSN.0, INSN.1, x, mask_type);
+        }
+    };
+    (@op $name:ident, vxu) => {
+        $crate::rvv_insns!(@op $name, vx);
+    };
+    (@op $name:ident, vi) => {
+        fn op(_: &[u8], rhs: &[u8], mask_type: $crate::runner::MaskType) {
+            let imm = i64::from_le_bytes(::core::convert::TryInto::try_into(rhs).unwrap());
+            $crate::rvv_op_i!(simm5, concat!(stringify!($name), ".vi"), imm, mask_type);
+        }
+    };
+    (@op $name:ident, viu) => {
+        fn op(_: &[u8], rhs: &[u8], mask_type: $crate::runner::MaskType) {
+            let imm = i64::from_le_bytes(::core::convert::TryInto::try_into(rhs).unwrap());
+            $crate::rvv_op_i!(uimm5, concat!(stringify!($name), ".vi"), imm, mask_type);
+        }
+    };
+    (@op $name:ident, mvv) => {
+        $crate::rvv_insns!(@op $name, vv);
+    };
+    (@op $name:ident, mvx) => {
+        $crate::rvv_insns!(@op $name, vx);
+    };
+    (@op $name:ident, mvi) => {
+        $crate::rvv_insns!(@op $name, vi);
+    };
+    (@run vv, $($args:expr),+) => {
+        $crate::runner::run_template_v_vv($($args),+)
+    };
+    (@run vx, $($args:expr),+) => {
+        $crate::runner::run_template_v_vx($($args),+)
+    };
+    (@run vxu, $($args:expr),+) => {
+        $crate::runner::run_template_v_vx($($args),+)
+    };
+    (@run vi, $exp:expr, $op:expr, $mask:expr, $desc:expr) => {
+        $crate::runner::run_template_v_vi($exp, $op, $mask, true, $desc)
+    };
+    (@run viu, $exp:expr, $op:expr, $mask:expr, $desc:expr) => {
+        $crate::runner::run_template_v_vi($exp, $op, $mask, false, $desc)
+    };
+    (@run mvv, $($args:expr),+) => {
+        $crate::runner::run_template_m_vv($($args),+)
+    };
+    (@run mvx, $($args:expr),+) => {
+        $crate::runner::run_template_m_vx($($args),+)
+    };
+    (@run mvi, $($args:expr),+) => {
+        $crate::runner::run_template_m_vi($($args),+)
+    };
+    (@exp vv, ref, $ref:path) => {
+        $crate::runner::expected_vv::<$ref>
+    };
+    (@exp vx, ref, $ref:path) => {
+        $crate::runner::expected_vx::<$ref>
+    };
+    (@exp vxu, ref, $ref:path) => {
+        $crate::runner::expected_vxu::<$ref>
+    };
+    (@exp vi, ref, $ref:path) => {
+        $crate::runner::expected_vi::<$ref>
+    };
+    (@exp viu, ref, $ref:path) => {
+        $crate::runner::expected_vi::<$ref>
+    };
+    (@exp $form:ident, exp, $exp:path) => {
+        $exp
+    };
+    (@suffix vv) => { "vv" };
+    (@suffix vx) => { "vx" };
+    (@suffix vxu) => { "vx" };
+    (@suffix vi) => { "vi" };
+    (@suffix viu) => { "vi" };
+    (@suffix mvv) => { "vv" };
+    (@suffix mvx) => { "vx" };
+    (@suffix mvi) => { "vi" };
+    (@mask masked) => {
+        true
+    };
+    (@mask unmasked) => {
+        false
+    };
+    (@case $name:ident, $form:ident, $mask:ident, $kind:ident, $ref:path, $test_pattern:ident) => {{
+        fn run() {
+            $crate::rvv_insns!(@op $name, $form);
+            $crate::rvv_insns!(
+                @run $form,
+                $crate::rvv_insns!(@exp $form, $kind, $ref),
+                op,
+                $crate::rvv_insns!(@mask $mask),
+                concat!(stringify!($name), ".", $crate::rvv_insns!(@suffix $form))
+            );
+        }
+        let case_name = $crate::misc::case_name(
+            module_path!(),
+            concat!(stringify!($name), ".", $crate::rvv_insns!(@suffix $form)),
+        );
+        $crate::misc::run_case(&case_name, run, $test_pattern);
+    }};
+    ($vis:vis fn $test:ident; $($name:ident, $($form:ident)|+, $mask:ident, $kind:ident = $ref:path;)*) => {
+        $vis fn $test(test_pattern: Option<&str>) {
+            $($($crate::rvv_insns!(@case $name, $form, $mask, $kind, $ref, test_pattern);)+)*
+        }
+    };
+}
@@ -2,8 +2,12 @@
 
 extern crate alloc;
 
+pub mod coverage;
+pub mod digest;
 pub mod intrinsic;
 pub mod intrinsic_setvl;
+pub mod isa;
 pub mod misc;
 pub mod rng;
 pub mod runner;
+pub mod vmodel;
@@ -1,12 +1,15 @@
+use alloc::format;
 use alloc::vec::Vec;
 use ckb_std::syscalls::debug;
 use core::arch::asm;
 use eint::{Eint, E16, E32, E64, E8};
-use rand::Rng;
+use rand::{Rng, RngCore};
 use rvv_asm::rvv_asm;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::digest::update_digest;
 use rvv_testcases::intrinsic::{
-    vle_v16, vle_v24, vle_v8, vloxei_v8, vlse_v8, vluxei_v8, vs1r_v8, vs2r_v8, vs4r_v8, vs8r_v8,
-    vse_v16, vse_v24, vse_v8, vsm_v_v8, vsoxei_v8, vsse_v8, vsuxei_v8,
+    vl8r_v8, vle_v16, vle_v24, vle_v8, vloxei_v8, vlse_v8, vluxei_v8, vs1r_v8, vs2r_v8, vs4r_v8,
+    vs8r_v8, vse_v16, vse_v24, vse_v8, vsm_v_v8, vsoxei_v8, vsse_v8, vsuxei_v8,
 };
 use rvv_testcases::log;
 use rvv_testcases::misc::MutSliceUtils;
@@ -58,6 +61,9 @@ fn test_unit_stride(sew: usize, lmul: i64, offset: i64, register: usize) {
         return;
     }
 
+    record_instruction(&format!("vle{}.v", sew), sew as u64, lmul, false);
+    record_instruction(&format!("vse{}.v", sew), sew as u64, lmul, false);
+
     let mut mem: Vec<u8> = Vec::new();
     mem.resize(res_vl as usize * sew / 8, 1);
 
@@ -129,6 +135,8 @@ fn test_stride(sew: usize, lmul: i64, stride: usize) {
 
     vlse_v8(sew as u64, &mem[..], stride as u64);
     vsse_v8(sew as u64, &mut mem2[..], stride as u64);
+    record_instruction(&format!("vlse{}.v", sew), sew as u64, lmul, false);
+    record_instruction(&format!("vsse{}.v", sew), sew as u64, lmul, false);
 
     for i in 0..vl as usize {
         let range = i * stride as usize..i * stride + sew / 8;
@@ -147,6 +155,92 @@ fn test_stride(sew: usize, lmul: i64, stride: usize) {
     }
 }
 
+// Reference model of strided accesses, elements are accessed in index order
+// so with overlapping stores the later element wins.
+fn strided_load_model(
+    mem: &[u8],
+    base: usize,
+    stride: i64,
+    sew_bytes: usize,
+    vl: usize,
+) -> Vec<u8> {
+    let mut res: Vec<u8> = Vec::new();
+    for i in 0..vl {
+        let addr = (base as i64 + i as i64 * stride) as usize;
+        res.extend_from_slice(&mem[addr..addr + sew_bytes]);
+    }
+    res
+}
+
+fn strided_store_model(mem: &mut [u8], base: usize, stride: i64, data: &[u8], sew_bytes: usize) {
+    for i in 0..data.len() / sew_bytes {
+        let addr = (base as i64 + i as i64 * stride) as usize;
+        mem[addr..addr + sew_bytes].copy_from_slice(&data[i * sew_bytes..(i + 1) * sew_bytes]);
+    }
+}
+
+// `stride` is in bytes, it can be 0, negative or smaller than the element
+fn test_stride_signed(sew: usize, lmul: i64, stride: i64) {
+    let vl = get_vl_by_lmul(sew, lmul);
+    if vl == 0 {
+        return;
+    }
+    let res_vl = vsetvl(vl as u64, sew as u64, lmul);
+    if res_vl == 0 {
+        return;
+    }
+    let vl = res_vl as usize;
+    let sew_bytes = sew / 8;
+
+    let span = (vl - 1) * stride.unsigned_abs() as usize + sew_bytes;
+    // element 0 is at the highest address with a negative stride
+    let base = if stride < 0 { span - sew_bytes } else { 0 };
+    // the store intrinsic wants vl * sew_bytes bytes from the base
+    let mem_len = span + vl * sew_bytes;
+
+    let mut rng = BestNumberRng::default();
+    let mut mem: Vec<u8> = Vec::new();
+    mem.resize(mem_len, 0);
+    rng.fill(&mut mem[..]);
+
+    vlse_v8(sew as u64, &mem[base..], stride as u64);
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(vl * sew_bytes, 0);
+    vse_v8(sew as u64, &mut result[..]);
+    let expected = strided_load_model(&mem, base, stride, sew_bytes, vl);
+    if expected != result {
+        log!(
+            "test_stride_signed() failed on vlse, sew = {}, lmul = {}, stride = {}",
+            sew,
+            lmul,
+            stride
+        );
+        log!("expected = {:0>2X?}, result = {:0>2X?}", expected, result);
+        panic!("Abort");
+    }
+
+    let mut data: Vec<u8> = Vec::new();
+    data.resize(vl * sew_bytes, 0);
+    rng.fill(&mut data[..]);
+    vle_v8(sew as u64, &data[..]);
+
+    let mut expected = mem.clone();
+    strided_store_model(&mut expected, base, stride, &data, sew_bytes);
+    vsse_v8(sew as u64, &mut mem[base..], stride as u64);
+    record_instruction(&format!("vlse{}.v", sew), sew as u64, lmul, false);
+    record_instruction(&format!("vsse{}.v", sew), sew as u64, lmul, false);
+    if expected != mem {
+        log!(
+            "test_stride_signed() failed on vsse, sew = {}, lmul = {}, stride = {}",
+            sew,
+            lmul,
+            stride
+        );
+        log!("expected = {:0>2X?}, result = {:0>2X?}", expected, mem);
+        panic!("Abort");
+    }
+}
+
 pub fn test_load_store() {
     for sew in [8, 16, 32, 64, 128, 256, 512, 1024] {
         for lmul in [-8, -4, -2, 1, 2, 4, 8] {
@@ -156,6 +250,19 @@ pub fn test_load_store() {
                 }
             }
             test_stride(sew, lmul, sew + 16);
+
+            // broadcast load, only the last element survives the store
+            test_stride_signed(sew, lmul, 0);
+            // walking backwards
+            let sew_bytes = sew as i64 / 8;
+            test_stride_signed(sew, lmul, -sew_bytes);
+            test_stride_signed(sew, lmul, -sew_bytes - 16);
+            // overlapping elements
+            if sew_bytes > 1 {
+                test_stride_signed(sew, lmul, 1);
+                test_stride_signed(sew, lmul, -sew_bytes / 2);
+                test_stride_signed(sew, lmul, sew_bytes / 2);
+            }
         }
     }
 }
@@ -248,8 +355,10 @@ fn test_indexed_unordered(sew: usize, offset_sew: usize, lmul: i64, test_ordered
     };
     if test_ordered {
         vloxei_v8(offset_sew as u64, &mem, &offset);
+        record_instruction(&format!("vloxei{}.v", offset_sew), sew as u64, lmul, false);
     } else {
         vluxei_v8(offset_sew as u64, &mem, &offset);
+        record_instruction(&format!("vluxei{}.v", offset_sew), sew as u64, lmul, false);
     }
     vse_v8(sew as u64, &mut result1);
 
@@ -296,8 +405,10 @@ fn test_indexed_unordered(sew: usize, offset_sew: usize, lmul: i64, test_ordered
     };
     if test_ordered {
         vsoxei_v8(offset_sew as u64, &mut result2, &offset);
+        record_instruction(&format!("vsoxei{}.v", offset_sew), sew as u64, lmul, false);
     } else {
         vsuxei_v8(offset_sew as u64, &mut result2, &offset);
+        record_instruction(&format!("vsuxei{}.v", offset_sew), sew as u64, lmul, false);
     }
 
     let expected2 = {
@@ -352,6 +463,124 @@ pub fn test_load_store_uxei() {
     }
 }
 
+// Active elements target the same slots (and for the ordered store, slots
+// shifted by half an element). vsoxei must write in element order so the last
+// element wins, vsuxei may keep any of the elements but never a mix of them.
+fn test_indexed_store_duplicate(sew: usize, offset_sew: usize, lmul: i64, ordered: bool) {
+    let lmul_value = if lmul > 0 {
+        lmul as f64
+    } else {
+        1.0 / -lmul as f64
+    };
+    let emul = offset_sew as f64 / sew as f64 * lmul_value;
+    if emul < 0.125 || emul > 8.0 {
+        return;
+    }
+    let vl = get_vl_by_lmul(sew, lmul);
+    if vl == 0 {
+        return;
+    }
+    let set_vl = vsetvl(vl as u64, sew as u64, lmul);
+    if set_vl == 0 {
+        return;
+    }
+    let vl = set_vl as usize;
+    let sew_byte = sew / 8;
+    let offset_byte = offset_sew / 8;
+    // 3 slots, the offsets fit in 8 bits for every valid EMUL
+    let slots = 3;
+
+    let mut rng = BestNumberRng::default();
+    let mut offsets: Vec<usize> = (0..vl)
+        .map(|_| {
+            let slot = rng.next_u64() as usize % slots;
+            if ordered && rng.next_u64() % 2 == 1 {
+                slot * sew_byte + sew_byte / 2
+            } else {
+                slot * sew_byte
+            }
+        })
+        .collect();
+    if vl > 1 {
+        offsets[vl - 1] = offsets[0];
+    }
+    let mut offset: Vec<u8> = Vec::new();
+    offset.resize(vl * offset_byte, 0);
+    for i in 0..vl {
+        offset[i * offset_byte..(i + 1) * offset_byte]
+            .copy_from_slice(&(offsets[i] as u64).to_le_bytes()[..offset_byte]);
+    }
+
+    // every element is different, tagged with its index
+    let mut data: Vec<u8> = Vec::new();
+    data.resize(vl * sew_byte, 0);
+    rng.fill(&mut data[..]);
+    for i in 0..vl {
+        data[i * sew_byte..i * sew_byte + 2].copy_from_slice(&(i as u16).to_le_bytes());
+    }
+
+    // the store checks the buffer against vl, only the first slots are targeted
+    let mut mem: Vec<u8> = Vec::new();
+    mem.resize(vl.max(slots + 1) * sew_byte, 0);
+    rng.fill(&mut mem[..]);
+    let mem_before = mem.clone();
+
+    vle_v8(sew as u64, &data);
+    if ordered {
+        vsoxei_v8(offset_sew as u64, &mut mem, &offset);
+        record_instruction(&format!("vsoxei{}.v", offset_sew), sew as u64, lmul, false);
+    } else {
+        vsuxei_v8(offset_sew as u64, &mut mem, &offset);
+        record_instruction(&format!("vsuxei{}.v", offset_sew), sew as u64, lmul, false);
+    }
+
+    let failed = if ordered {
+        let mut expected = mem_before.clone();
+        for i in 0..vl {
+            expected[offsets[i]..offsets[i] + sew_byte]
+                .copy_from_slice(&data[i * sew_byte..(i + 1) * sew_byte]);
+        }
+        expected != mem
+    } else {
+        (0..mem.len() / sew_byte).any(|slot| {
+            let start = slot * sew_byte;
+            let range = start..start + sew_byte;
+            let result = &mem[range.clone()];
+            let mut candidates = (0..vl).filter(|i| offsets[*i] == start).peekable();
+            if candidates.peek().is_none() {
+                result != &mem_before[range]
+            } else {
+                !candidates.any(|i| result == &data[i * sew_byte..(i + 1) * sew_byte])
+            }
+        })
+    };
+    if failed {
+        log!(
+            "test_indexed_store_duplicate() failed, sew = {}, offset_sew = {}, lmul = {}, ordered = {}",
+            sew,
+            offset_sew,
+            lmul,
+            ordered
+        );
+        log!("offsets = {:?}", offsets);
+        log!("data = {:0>2X?}", data);
+        log!("mem_before = {:0>2X?}", mem_before);
+        log!("mem = {:0>2X?}", mem);
+        panic!("Abort");
+    }
+}
+
+pub fn test_indexed_store_duplicate_index() {
+    for sew in [128, 256, 512, 1024] {
+        for offset_sew in [8, 16, 32, 64] {
+            for lmul in [-8, -4, -2, 1, 2, 4, 8] {
+                test_indexed_store_duplicate(sew, offset_sew, lmul, false);
+                test_indexed_store_duplicate(sew, offset_sew, lmul, true);
+            }
+        }
+    }
+}
+
 fn test_vlm_v(mem: &Vec<u8>, sew: usize, lmul: i64) {
     fill_all_regisert();
 
@@ -395,6 +624,9 @@ fn test_vlm_v(mem: &Vec<u8>, sew: usize, lmul: i64) {
         vsm_v_v8(&mut result1);
     }
 
+    record_instruction("vlm.v", sew as u64, lmul, false);
+    record_instruction("vsm.v", sew as u64, lmul, false);
+
     let expected1 = &mem[..ceil_len];
 
     if expected1 != result1 {
@@ -469,6 +701,86 @@ pub fn test_vector_unit_stride() {
     }
 }
 
+// With vl = 0, loads must not update the destination register group and
+// stores must not write memory.
+fn test_zero_vl(sew: usize, lmul: i64) {
+    if vsetvl(1, sew as u64, lmul) == 0 {
+        return;
+    }
+    let lmul_value = if lmul > 0 {
+        lmul as f64
+    } else {
+        1.0 / -lmul as f64
+    };
+    // the index EMUL of offset_sew = 8 must be in [1/8, 8]
+    let emul = 8.0 / sew as f64 * lmul_value;
+    let indexed = emul >= 0.125 && emul <= 8.0;
+    let sew = sew as u64;
+    let stride = sew / 8 + 16;
+
+    let mut rng = BestNumberRng::default();
+    let mut mem = [0u8; VLEN];
+    rng.fill(&mut mem[..]);
+    // offset_sew = 8, every index stays inside `mem`
+    let mut offset = [0u8; VLEN];
+    rng.fill(&mut offset[..]);
+
+    let mut before = [0u8; VLEN];
+    rng.fill(&mut before[..]);
+    vl8r_v8(&before);
+
+    let vl = vsetvl(0, sew, lmul);
+    assert_eq!(vl, 0);
+
+    vle_v8(sew, &mem[..]);
+    vlse_v8(sew, &mem[..], stride);
+    if indexed {
+        vluxei_v8(8, &mem[..], &offset[..]);
+        vloxei_v8(8, &mem[..], &offset[..]);
+    }
+    unsafe {
+        rvv_asm!("mv t0, {}", "vlm.v v8, (t0)", in (reg) mem.as_ptr());
+    }
+
+    let mut after = [0u8; VLEN];
+    vs8r_v8(&mut after[..]);
+    if before != after {
+        log!(
+            "test_zero_vl() failed, destination updated by load, sew = {}, lmul = {}",
+            sew,
+            lmul
+        );
+        log!("before = {:0>2X?}, after = {:0>2X?}", before, after);
+        panic!("Abort");
+    }
+
+    let mut mem2 = mem;
+    vse_v8(sew, &mut mem2[..]);
+    vsse_v8(sew, &mut mem2[..], stride);
+    if indexed {
+        vsuxei_v8(8, &mut mem2[..], &offset[..]);
+        vsoxei_v8(8, &mut mem2[..], &offset[..]);
+    }
+    vsm_v_v8(&mut mem2[..]);
+    if mem != mem2 {
+        log!(
+            "test_zero_vl() failed, memory written by store, sew = {}, lmul = {}",
+            sew,
+            lmul
+        );
+        log!("expected = {:0>2X?}, result = {:0>2X?}", mem, mem2);
+        panic!("Abort");
+    }
+}
+
+pub fn test_load_store_zero_vl() {
+    for sew in [8, 16, 32, 64, 128, 256, 512, 1024] {
+        for lmul in [-8, -4, -2, 1, 2, 4, 8] {
+            test_zero_vl(sew, lmul);
+        }
+    }
+}
+
 fn store_whole_v8(whole: usize, out_buf: &mut [u8]) {
     match whole {
         1 => vs1r_v8(out_buf),
@@ -599,6 +911,13 @@ fn check_whole(
 
     let mut result = [0u8; 2048];
     store_whole_v8(store_whole, &mut result);
+    record_instruction(
+        &format!("vl{}re{}.v", load_whole, load_whole_len),
+        sew as u64,
+        lmul,
+        false,
+    );
+    record_instruction(&format!("vs{}r.v", store_whole), sew as u64, lmul, false);
 
     let expected = get_whole_expected(load_whole, store_whole, &mem);
     if result != expected.as_slice() {
@@ -644,3 +963,169 @@ pub fn test_whole_load_store() {
         }
     }
 }
+
+#[derive(Clone, Copy, PartialEq)]
+enum VtypeState {
+    ZeroVl,
+    TinyVl,
+    // vill is set
+    Illegal,
+}
+
+fn set_vtype_state(state: VtypeState) {
+    match state {
+        VtypeState::ZeroVl => {
+            vsetvl(0, 64, 1);
+        }
+        VtypeState::TinyVl => {
+            vsetvl(1, 1024, 8);
+        }
+        VtypeState::Illegal => {
+            // the reserved LMUL encoding 0b100
+            let vtype: u64 = 0b100;
+            unsafe {
+                rvv_asm!(
+                    "mv t1, {}",
+                    "mv t2, {}",
+                    "vsetvl t0, t1, t2",
+                    in (reg) 8u64,
+                    in (reg) vtype,
+                );
+            }
+        }
+    }
+}
+
+fn read_vl_vtype() -> (u64, u64) {
+    let vl: u64;
+    let vtype: u64;
+    unsafe {
+        asm!("csrr {}, 0xC20", "csrr {}, 0xC21", out (reg) vl, out (reg) vtype);
+    }
+    (vl, vtype)
+}
+
+fn move_whole_v24(whole: usize) {
+    unsafe {
+        match whole {
+            1 => {
+                rvv_asm!("vmv1r.v v24, v8");
+            }
+            2 => {
+                rvv_asm!("vmv2r.v v24, v8");
+            }
+            4 => {
+                rvv_asm!("vmv4r.v v24, v8");
+            }
+            8 => {
+                rvv_asm!("vmv8r.v v24, v8");
+            }
+            _ => panic!("Abort"),
+        }
+    }
+}
+
+fn store_whole_v24(whole: usize, out_buf: &mut [u8]) {
+    assert!(out_buf.len() >= VLEN / 8 * whole);
+    let p = out_buf.as_ptr();
+    unsafe {
+        match whole {
+            1 => {
+                rvv_asm!("mv t0, {}", "vs1r.v v24, (t0)", in (reg) p);
+            }
+            2 => {
+                rvv_asm!("mv t0, {}", "vs2r.v v24, (t0)", in (reg) p);
+            }
+            4 => {
+                rvv_asm!("mv t0, {}", "vs4r.v v24, (t0)", in (reg) p);
+            }
+            8 => {
+                rvv_asm!("mv t0, {}", "vs8r.v v24, (t0)", in (reg) p);
+            }
+            _ => panic!("Abort"),
+        }
+    }
+    update_digest(out_buf);
+}
+
+fn check_whole_ignore_vtype(
+    desc: &str,
+    state: VtypeState,
+    whole: usize,
+    mem: &[u8],
+    result: &[u8],
+) {
+    let len = VLEN / 8 * whole;
+    if mem[..len] != result[..len] || result[len..].iter().any(|x| *x != 0xAA) {
+        log!(
+            "Failed on {} with {} registers, vl = 0: {}, tiny vl: {}, vill: {}",
+            desc,
+            whole,
+            state == VtypeState::ZeroVl,
+            state == VtypeState::TinyVl,
+            state == VtypeState::Illegal
+        );
+        log!(
+            "More infomation:\nresult: {:0>2X?}\nexpected: {:0>2X?}",
+            result,
+            &mem[..len]
+        );
+        panic!("Abort");
+    }
+}
+
+// Whole register loads, stores and moves ignore vl and vtype, they also work
+// when vill is set.
+fn whole_register_ignore_vtype(state: VtypeState, whole: usize, whole_len: usize) {
+    let mem = {
+        let mut rng = BestNumberRng::default();
+        let mut buf = [1u8; 2048];
+        rng.fill(&mut buf[..]);
+        buf
+    };
+
+    set_vtype_state(state);
+    let before = read_vl_vtype();
+    if state == VtypeState::Illegal && before.1 >> 63 != 1 {
+        log!("vill is not set by vsetvl, vtype = {:#X}", before.1);
+        panic!("Abort");
+    }
+
+    load_whole_v8(whole, whole_len, &mem);
+    let mut result = [0xAAu8; 2048];
+    store_whole_v8(whole, &mut result);
+    check_whole_ignore_vtype(
+        &format!("vl{}re{}.v/vs{}r.v", whole, whole_len, whole),
+        state,
+        whole,
+        &mem,
+        &result,
+    );
+
+    move_whole_v24(whole);
+    let mut result = [0xAAu8; 2048];
+    store_whole_v24(whole, &mut result);
+    check_whole_ignore_vtype(&format!("vmv{}r.v", whole), state, whole, &mem, &result);
+
+    let after = read_vl_vtype();
+    if before != after {
+        log!(
+            "vl/vtype changed by whole register instructions: ({}, {:#X}) -> ({}, {:#X})",
+            before.0,
+            before.1,
+            after.0,
+            after.1
+        );
+        panic!("Abort");
+    }
+}
+
+pub fn test_whole_register_ignore_vtype() {
+    for state in [VtypeState::ZeroVl, VtypeState::TinyVl, VtypeState::Illegal] {
+        for whole in [1, 2, 4, 8] {
+            for whole_len in [8, 16, 32, 64] {
+                whole_register_ignore_vtype(state, whole, whole_len);
+            }
+        }
+    }
+}
@@ -5,18 +5,26 @@
 #![feature(panic_info_message)]
 #![feature(unchecked_math)]
 #![feature(asm_sym)]
+#![feature(asm_const)]
 
 mod adc_sbc_cases;
+mod bigint_chain_cases;
 mod count_population_in_mask_cases;
+mod cross_sew_cases;
 mod integer_extension_cases;
 mod integer_merge_cases;
 mod integer_move_cases;
 mod integer_scalar_move_cases;
 mod load_store_cases;
 mod mask_register_logical_cases;
+mod masked_memory_access_cases;
+mod memory_boundary_cases;
+mod metamorphic_cases;
 mod misc_cases;
+mod montgomery_cases;
 mod narrowing_fixed_point_clip_cases;
 mod narrowing_integer_right_shift_cases;
+mod sequence_cases;
 mod set_before_first_cases;
 mod set_including_first_cases;
 mod set_only_first_cases;
@@ -48,53 +56,119 @@ mod vwop_vx_cases;
 mod vwop_wv_cases;
 mod vwop_wx_cases;
 
+mod zvbb_cases;
+mod zvbc_cases;
+
+use alloc::format;
+use alloc::string::{String, ToString};
 use ckb_std::cstr_core::CStr;
 use ckb_std::default_alloc;
-use ckb_std::syscalls::debug;
+use ckb_std::syscalls::{current_cycles, debug};
 use core::arch::asm;
 use core::slice::from_raw_parts;
 use core::stringify;
-use rvv_testcases::misc::{is_full, is_verbose, set_full, set_verbose};
-use rvv_testcases::rng::{customize_seed, get_seed};
+use rvv_testcases::coverage::print_coverage_report;
+use rvv_testcases::intrinsic::reset_vsetvl_count;
+use rvv_testcases::misc::{
+    get_avl_strategy, is_coverage, is_exact, is_full, is_list, is_verbose, set_avl_strategy,
+    set_coverage, set_digest, set_exact, set_fault_case, set_full, set_list, set_memory_end,
+    set_verbose, AvlStrategy, FaultCase,
+};
+use rvv_testcases::rng::{customize_seed, get_seed, iteration_seed};
+use rvv_testcases::runner::reset_poison_seed;
 use rvv_testcases::{log, test_case};
 
 ckb_std::entry!(program_entry);
 default_alloc!();
 
 fn program_entry(argc: u64, argv: *const *const u8) -> i8 {
-    let mut test_pattern = Option::<&str>::None;
+    // owned, the cases touching the end of memory overwrite the loader's argv
+    let mut test_pattern = Option::<String>::None;
+    let mut iterations = Option::<u64>::None;
+    let mut cycles_budget = Option::<u64>::None;
 
     let args = unsafe { from_raw_parts(argv, argc as usize) };
+    if argc > 0 {
+        let first = unsafe { CStr::from_ptr(args[0]) };
+        set_memory_end(args[0] as usize + first.to_bytes().len() + 1);
+    }
     for i in 0..argc as usize {
         let data = unsafe { CStr::from_ptr(args[i]) }.to_str().unwrap();
         if data.find("--case").is_some() {
             let pos = data.find("--case=").unwrap() + 7;
-            test_pattern = Some(&data[pos..data.len()]);
+            test_pattern = Some(data[pos..data.len()].to_string());
         } else if data.find("--verbose").is_some() {
             set_verbose(true);
         } else if data.find("--full").is_some() {
             set_full(true);
+        } else if data.find("--coverage").is_some() {
+            set_coverage(true);
+        } else if data.find("--list").is_some() {
+            set_list(true);
+        } else if data.find("--exact").is_some() {
+            set_exact(true);
+        } else if data.find("--digest").is_some() {
+            set_digest(true);
+        } else if data.find("--avl=").is_some() {
+            let pos = data.find("--avl=").unwrap() + 6;
+            match AvlStrategy::parse(&data[pos..data.len()]) {
+                Some(strategy) => set_avl_strategy(strategy),
+                None => {
+                    log!("Invalid avl strategy: {}", &data[pos..data.len()]);
+                    panic!("Abort");
+                }
+            }
+        } else if data.find("--fault=").is_some() {
+            let pos = data.find("--fault=").unwrap() + 8;
+            match &data[pos..data.len()] {
+                "load" => set_fault_case(Some(FaultCase::Load)),
+                "store" => set_fault_case(Some(FaultCase::Store)),
+                _ => {
+                    log!("Invalid fault case: {}", &data[pos..data.len()]);
+                    panic!("Abort");
+                }
+            }
         } else if data.find("--seed=").is_some() {
             let pos = data.find("--seed=").unwrap() + 7;
             customize_seed(data[pos..data.len()].parse::<u64>().unwrap())
+        } else if data.find("--iterations=").is_some() {
+            let pos = data.find("--iterations=").unwrap() + 13;
+            iterations = Some(data[pos..data.len()].parse::<u64>().unwrap());
+        } else if data.find("--cycles-budget=").is_some() {
+            let pos = data.find("--cycles-budget=").unwrap() + 16;
+            cycles_budget = Some(data[pos..data.len()].parse::<u64>().unwrap());
         }
     }
 
     log!(
-        "--StartTesting, case: {:?}, verbose: {}, fill: {}, seed: {}",
+        "--StartTesting, case: {:?}, verbose: {}, fill: {}, coverage: {}, avl: {}, seed: {}",
         test_pattern,
         is_verbose(),
         is_full(),
+        is_coverage(),
+        get_avl_strategy(),
         get_seed()
     );
 
+    match iterations {
+        Some(n) if !is_list() => run_iterations(n, cycles_budget, test_pattern.as_deref()),
+        _ => run_cases(test_pattern.as_deref()),
+    }
+
+    if is_coverage() && !is_list() {
+        print_coverage_report();
+    }
+    0
+}
+
+fn run_cases(test_pattern: Option<&str>) {
     test_case!(vsetvl_cases::test_vsetvl, test_pattern);
 
     test_case!(misc_cases::test_add, test_pattern);
     test_case!(misc_cases::test_add_array, test_pattern);
-    test_case!(vop_vv_cases::test_vop_vv, test_pattern);
-    test_case!(vop_vx_cases::test_vop_vx, test_pattern);
-    test_case!(vop_vi_cases::test_vop_vi, test_pattern);
+    vop_vv_cases::test_vop_vv(test_pattern);
+    vop_vx_cases::test_vop_vx(test_pattern);
+    vop_vi_cases::test_vop_vi(test_pattern);
     test_case!(vwop_vv_cases::test_vwop_vv, test_pattern);
     test_case!(vwop_wv_cases::test_vwop_wv, test_pattern);
     test_case!(vwop_vx_cases::test_vwop_vx, test_pattern);
@@ -103,10 +177,7 @@ fn program_entry(argc: u64, argv: *const *const u8) -> i8 {
         integer_extension_cases::test_integer_extension,
         test_pattern
     );
-    test_case!(
-        single_width_averaging_cases::test_single_width_averaging_add_and_subtract,
-        test_pattern
-    );
+    single_width_averaging_cases::test_single_width_averaging_add_and_subtract(test_pattern);
     test_case!(
         single_width_shift_cases::test_single_width_shift,
         test_pattern
@@ -167,18 +238,35 @@ fn program_entry(argc: u64, argv: *const *const u8) -> i8 {
     );
     test_case!(load_store_cases::test_load_store, test_pattern);
     test_case!(load_store_cases::test_load_store_uxei, test_pattern);
+    test_case!(
+        load_store_cases::test_indexed_store_duplicate_index,
+        test_pattern
+    );
     test_case!(load_store_cases::test_vector_unit_stride, test_pattern);
     test_case!(load_store_cases::test_whole_load_store, test_pattern);
+    test_case!(
+        load_store_cases::test_whole_register_ignore_vtype,
+        test_pattern
+    );
+    test_case!(load_store_cases::test_load_store_zero_vl, test_pattern);
+    test_case!(cross_sew_cases::test_cross_sew, test_pattern);
+    test_case!(memory_boundary_cases::test_misaligned_access, test_pattern);
+    test_case!(memory_boundary_cases::test_memory_end, test_pattern);
+    test_case!(
+        masked_memory_access_cases::test_masked_memory_access,
+        test_pattern
+    );
     test_case!(integer_merge_cases::test_integer_merge, test_pattern);
     test_case!(adc_sbc_cases::test_adc_sbc, test_pattern);
+    test_case!(bigint_chain_cases::test_bigint_chain, test_pattern);
+    test_case!(montgomery_cases::test_montgomery, test_pattern);
+    test_case!(metamorphic_cases::test_metamorphic, test_pattern);
+    test_case!(sequence_cases::test_sequence, test_pattern);
     test_case!(vector_compress_cases::test_vector_compress, test_pattern);
     test_case!(vector_slide_cases::test_vector_slide_up, test_pattern);
     test_case!(vector_slide_cases::test_vector_slide_down, test_pattern);
     test_case!(vector_register_gather_cases::test_vrgatherer, test_pattern);
-    test_case!(
-        single_width_scaling_shift::test_single_with_scaling_shift,
-        test_pattern
-    );
+    single_width_scaling_shift::test_single_with_scaling_shift(test_pattern);
     test_case!(
         single_saturating_add_subtract_cases::test_single_saturating_add_subtract,
         test_pattern
@@ -187,6 +275,52 @@ fn program_entry(argc: u64, argv: *const *const u8) -> i8 {
         narrowing_fixed_point_clip_cases::test_narrowing_fixed_point_clip,
         test_pattern
     );
+    test_case!(zvbb_cases::test_zvbb, test_pattern);
+    test_case!(zvbc_cases::test_zvbc, test_pattern);
 
-    0
+    // must be the last one, it doesn't return with --fault
+    test_case!(memory_boundary_cases::test_memory_end_fault, test_pattern);
+}
+
+// Reruns the selected cases, each iteration with its own seed drawn from the
+// master seed `--seed`. The last "iteration" line before a failure has the
+// arguments reproducing it.
+fn run_iterations(iterations: u64, cycles_budget: Option<u64>, test_pattern: Option<&str>) {
+    let master_seed = get_seed();
+    let case = match test_pattern {
+        Some(pattern) if is_exact() => format!(" --case={} --exact", pattern),
+        Some(pattern) => format!(" --case={}", pattern),
+        None => String::new(),
+    };
+    // cycles of the most expensive iteration so far
+    let mut longest = 0;
+    for i in 0..iterations {
+        let start = current_cycles();
+        if let Some(budget) = cycles_budget {
+            if start + longest > budget {
+                log!(
+                    "cycles budget {} reached, {} of {} iterations done",
+                    budget,
+                    i,
+                    iterations
+                );
+                return;
+            }
+        }
+        let seed = iteration_seed(master_seed, i);
+        customize_seed(seed);
+        reset_vsetvl_count();
+        reset_poison_seed();
+        log!(
+            "iteration {}/{}, reproduce with: --seed={} --avl={}{}{}",
+            i + 1,
+            iterations,
+            seed,
+            get_avl_strategy(),
+            if is_full() { " --full" } else { "" },
+            case
+        );
+        run_cases(test_pattern);
+        longest = longest.max(current_cycles() - start);
+    }
 }
@@ -0,0 +1,339 @@
+use alloc::format;
+use alloc::vec::Vec;
+use ckb_std::syscalls::debug;
+use core::arch::asm;
+use core::slice::from_raw_parts;
+use rand::Rng;
+use rvv_asm::rvv_asm;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::digest::update_digest;
+use rvv_testcases::intrinsic::{vl1r_v0, vle_v16, vle_v8, vse_v8, vsetvl};
+use rvv_testcases::log;
+use rvv_testcases::misc::{
+    get_bit_in_slice, is_verbose, memory_end, set_bit_in_slice, MEMORY_SIZE, VLEN,
+};
+use rvv_testcases::rng::BestNumberRng;
+
+// Added to any base address on the heap, it's outside of the memory. It fits in
+// 32 bit offsets.
+const OUT_OF_MEMORY: u64 = 0xFFFF_0000;
+
+fn check(desc: &str, sew: u64, lmul: i64, mask: &[u8], expected: &[u8], result: &[u8]) {
+    if expected != result {
+        log!(
+            "[sew = {}, lmul = {}, describe = {}] unexpected values found",
+            sew,
+            lmul,
+            desc
+        );
+        log!("-mask: {:0>2X?}", mask);
+        log!("-expected: {:0>2X?}", expected);
+        log!("-result: {:0>2X?}", result);
+        panic!("Abort");
+    }
+}
+
+// active elements from `active`, inactive ones from `inactive`
+fn merge_by_mask(active: &[u8], inactive: &[u8], mask: &[u8], sew_bytes: usize) -> Vec<u8> {
+    let mut res = inactive.to_vec();
+    for i in 0..inactive.len() / sew_bytes {
+        if get_bit_in_slice(mask, i) == 1 {
+            res[i * sew_bytes..(i + 1) * sew_bytes]
+                .copy_from_slice(&active[i * sew_bytes..(i + 1) * sew_bytes]);
+        }
+    }
+    res
+}
+
+fn masked_vle(sew: u64, p: usize) {
+    unsafe {
+        match sew {
+            8 => {
+                rvv_asm!("mv t0, {}", "vle8.v v8, (t0), v0.t", in (reg) p);
+            }
+            16 => {
+                rvv_asm!("mv t0, {}", "vle16.v v8, (t0), v0.t", in (reg) p);
+            }
+            32 => {
+                rvv_asm!("mv t0, {}", "vle32.v v8, (t0), v0.t", in (reg) p);
+            }
+            64 => {
+                rvv_asm!("mv t0, {}", "vle64.v v8, (t0), v0.t", in (reg) p);
+            }
+            128 => {
+                rvv_asm!("mv t0, {}", "vle128.v v8, (t0), v0.t", in (reg) p);
+            }
+            256 => {
+                rvv_asm!("mv t0, {}", "vle256.v v8, (t0), v0.t", in (reg) p);
+            }
+            512 => {
+                rvv_asm!("mv t0, {}", "vle512.v v8, (t0), v0.t", in (reg) p);
+            }
+            1024 => {
+                rvv_asm!("mv t0, {}", "vle1024.v v8, (t0), v0.t", in (reg) p);
+            }
+            _ => {
+                panic!("Invalid sew");
+            }
+        }
+    }
+}
+
+fn masked_vlse(sew: u64, p: usize, stride: u64) {
+    unsafe {
+        match sew {
+            8 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse8.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            16 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse16.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            32 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse32.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            64 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse64.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            128 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse128.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            256 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse256.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            512 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse512.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            1024 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vlse1024.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            _ => {
+                panic!("Invalid sew");
+            }
+        }
+    }
+}
+
+fn masked_vsse(sew: u64, p: usize, stride: u64) {
+    unsafe {
+        match sew {
+            8 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse8.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            16 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse16.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            32 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse32.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            64 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse64.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            128 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse128.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            256 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse256.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            512 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse512.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            1024 => {
+                rvv_asm!("mv t0, {}", "mv t1, {}", "vsse1024.v v8, (t0), t1, v0.t", in (reg) p, in (reg) stride);
+            }
+            _ => {
+                panic!("Invalid sew");
+            }
+        }
+    }
+}
+
+// the offsets are in v16
+fn masked_indexed(offset_sew: u64, p: usize, store: bool, ordered: bool) {
+    unsafe {
+        match (offset_sew, store, ordered) {
+            (32, false, false) => {
+                rvv_asm!("mv t0, {}", "vluxei32.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (32, false, true) => {
+                rvv_asm!("mv t0, {}", "vloxei32.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (32, true, false) => {
+                rvv_asm!("mv t0, {}", "vsuxei32.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (32, true, true) => {
+                rvv_asm!("mv t0, {}", "vsoxei32.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (64, false, false) => {
+                rvv_asm!("mv t0, {}", "vluxei64.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (64, false, true) => {
+                rvv_asm!("mv t0, {}", "vloxei64.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (64, true, false) => {
+                rvv_asm!("mv t0, {}", "vsuxei64.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            (64, true, true) => {
+                rvv_asm!("mv t0, {}", "vsoxei64.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            _ => {
+                panic!("Invalid offset_sew = {}", offset_sew);
+            }
+        }
+    }
+}
+
+// Unit-stride load at the end of memory, the inactive elements are past it.
+fn test_unit_stride(sew: u64, lmul: i64) {
+    let vl = vsetvl(32, sew, lmul) as usize;
+    if vl < 2 {
+        return;
+    }
+    let sew_bytes = (sew / 8) as usize;
+    let active = vl / 2;
+
+    let mut mask = [0u8; VLEN / 8];
+    for i in 0..active {
+        set_bit_in_slice(&mut mask, i, 1);
+    }
+    let mut rng = BestNumberRng::default();
+    let mut before: Vec<u8> = Vec::new();
+    before.resize(vl * sew_bytes, 0);
+    rng.fill(&mut before[..]);
+
+    let base = memory_end() - active * sew_bytes;
+    let mut expected = before.clone();
+    let tail = unsafe { from_raw_parts(base as *const u8, active * sew_bytes) };
+    expected[..active * sew_bytes].copy_from_slice(tail);
+
+    vle_v8(sew, &before);
+    vl1r_v0(&mask);
+    masked_vle(sew, base);
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(vl * sew_bytes, 0);
+    vse_v8(sew, &mut result);
+    record_instruction(&format!("vle{}.v", sew), sew, lmul, true);
+    check("vle, memory end", sew, lmul, &mask, &expected, &result);
+}
+
+// Only element 0 can be in the memory with a stride of the memory size.
+fn test_stride(sew: u64, lmul: i64, first_active: bool) {
+    let vl = vsetvl(32, sew, lmul) as usize;
+    let sew_bytes = (sew / 8) as usize;
+    let stride = MEMORY_SIZE as u64;
+
+    let mut mask = [0u8; VLEN / 8];
+    set_bit_in_slice(&mut mask, 0, first_active as u8);
+    let mut rng = BestNumberRng::default();
+    let mut mem: Vec<u8> = Vec::new();
+    mem.resize(vl * sew_bytes, 0);
+    rng.fill(&mut mem[..]);
+    let mut before: Vec<u8> = Vec::new();
+    before.resize(vl * sew_bytes, 0);
+    rng.fill(&mut before[..]);
+
+    vle_v8(sew, &before);
+    vl1r_v0(&mask);
+    masked_vlse(sew, mem.as_ptr() as usize, stride);
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(vl * sew_bytes, 0);
+    vse_v8(sew, &mut result);
+    record_instruction(&format!("vlse{}.v", sew), sew, lmul, true);
+    let expected = merge_by_mask(&mem, &before, &mask, sew_bytes);
+    check("vlse", sew, lmul, &mask, &expected, &result);
+
+    // v8 holds `expected`
+    let mut expected_mem = mem.clone();
+    if first_active {
+        expected_mem[..sew_bytes].copy_from_slice(&expected[..sew_bytes]);
+    }
+    masked_vsse(sew, mem.as_ptr() as usize, stride);
+    update_digest(&mem);
+    record_instruction(&format!("vsse{}.v", sew), sew, lmul, true);
+    check("vsse", sew, lmul, &mask, &expected_mem, &mem);
+}
+
+// Active elements access their own slot in the buffer. Inactive elements
+// point either outside of the memory or to their own slot, which must stay
+// untouched.
+fn test_indexed(sew: u64, offset_sew: u64, lmul: i64, ordered: bool) {
+    let lmul_value = if lmul > 0 {
+        lmul as f64
+    } else {
+        1.0 / -lmul as f64
+    };
+    let emul = offset_sew as f64 / sew as f64 * lmul_value;
+    if emul < 0.125 || emul > 8.0 {
+        return;
+    }
+    let vl = vsetvl(32, sew, lmul) as usize;
+    if vl == 0 {
+        return;
+    }
+    let sew_bytes = (sew / 8) as usize;
+    let offset_bytes = (offset_sew / 8) as usize;
+
+    let mut rng = BestNumberRng::default();
+    let mut mask = [0u8; VLEN / 8];
+    rng.fill_mask(&mut mask[..]);
+    let mut offset: Vec<u8> = Vec::new();
+    offset.resize(vl * offset_bytes, 0);
+    for i in 0..vl {
+        let val = if get_bit_in_slice(&mask, i) == 0 && i % 2 == 0 {
+            OUT_OF_MEMORY
+        } else {
+            (i * sew_bytes) as u64
+        };
+        offset[i * offset_bytes..(i + 1) * offset_bytes]
+            .copy_from_slice(&val.to_le_bytes()[..offset_bytes]);
+    }
+
+    let mut mem: Vec<u8> = Vec::new();
+    mem.resize(vl * sew_bytes, 0);
+    rng.fill(&mut mem[..]);
+    let mut before: Vec<u8> = Vec::new();
+    before.resize(vl * sew_bytes, 0);
+    rng.fill(&mut before[..]);
+
+    vle_v16(offset_sew, &offset);
+    vle_v8(sew, &before);
+    vl1r_v0(&mask);
+    masked_indexed(offset_sew, mem.as_ptr() as usize, false, ordered);
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(vl * sew_bytes, 0);
+    vse_v8(sew, &mut result);
+    let name = if ordered { "vloxei" } else { "vluxei" };
+    record_instruction(&format!("{}{}.v", name, offset_sew), sew, lmul, true);
+    let expected = merge_by_mask(&mem, &before, &mask, sew_bytes);
+    check(name, sew, lmul, &mask, &expected, &result);
+
+    let mut data: Vec<u8> = Vec::new();
+    data.resize(vl * sew_bytes, 0);
+    rng.fill(&mut data[..]);
+    vle_v8(sew, &data);
+    let expected = merge_by_mask(&data, &mem, &mask, sew_bytes);
+    masked_indexed(offset_sew, mem.as_ptr() as usize, true, ordered);
+    update_digest(&mem);
+    let name = if ordered { "vsoxei" } else { "vsuxei" };
+    record_instruction(&format!("{}{}.v", name, offset_sew), sew, lmul, true);
+    check(name, sew, lmul, &mask, &expected, &mem);
+}
+
+pub fn test_masked_memory_access() {
+    for sew in [8, 16, 32, 64, 128, 256, 512, 1024] {
+        for lmul in [-8, -4, -2, 1, 2, 4, 8] {
+            if vsetvl(1, sew, lmul) == 0 {
+                continue;
+            }
+            if is_verbose() {
+                log!("run with sew = {}, lmul = {}", sew, lmul);
+            }
+            test_unit_stride(sew, lmul);
+            test_stride(sew, lmul, true);
+            test_stride(sew, lmul, false);
+            for offset_sew in [32, 64] {
+                test_indexed(sew, offset_sew, lmul, false);
+                test_indexed(sew, offset_sew, lmul, true);
+            }
+        }
+    }
+}
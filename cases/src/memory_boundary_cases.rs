@@ -0,0 +1,339 @@
+use alloc::vec::Vec;
+use ckb_std::syscalls::debug;
+use core::arch::asm;
+use core::slice::from_raw_parts_mut;
+use rand::Rng;
+use rvv_asm::rvv_asm;
+use rvv_testcases::intrinsic::{
+    vle_v8, vloxei_v8, vlse_v8, vluxei_v8, vse_v8, vsetvl, vsoxei_v8, vsse_v8, vsuxei_v8,
+};
+use rvv_testcases::log;
+use rvv_testcases::misc::{get_fault_case, is_verbose, memory_end, FaultCase, VLEN};
+use rvv_testcases::rng::BestNumberRng;
+
+// larger than any VLMAX, vsetvl returns VLMAX
+const AVL_MAX: u64 = VLEN as u64 * 8;
+// the bytes below the memory end before the faulting store
+const FAULT_MARKER: u8 = 0xA5;
+
+// `len` bytes of `buf`, starting `misalign` bytes past an `align` boundary
+fn misaligned_slice(buf: &mut Vec<u8>, len: usize, align: usize, misalign: usize) -> &mut [u8] {
+    buf.resize(len + align * 2, 0);
+    let base = buf.as_ptr() as usize;
+    let begin = (align - base % align) % align + misalign;
+    &mut buf[begin..begin + len]
+}
+
+fn check(desc: &str, sew: u64, address: usize, expected: &[u8], result: &[u8]) {
+    if expected != result {
+        log!(
+            "[sew = {}, describe = {}] unexpected values found, address = {:#X}",
+            sew,
+            desc,
+            address
+        );
+        log!("-expected: {:0>2X?}", expected);
+        log!("-result: {:0>2X?}", result);
+        panic!("Abort");
+    }
+}
+
+// offsets of the elements in reversed order, `step` bytes apart
+fn reversed_offsets(vl: usize, step: usize, offset_sew: u64) -> Vec<u8> {
+    let offset_bytes = (offset_sew / 8) as usize;
+    let mut offsets: Vec<u8> = Vec::new();
+    offsets.resize(vl * offset_bytes, 0);
+    for i in 0..vl {
+        let offset = ((vl - 1 - i) * step) as u64;
+        offsets[i * offset_bytes..(i + 1) * offset_bytes]
+            .copy_from_slice(&offset.to_le_bytes()[..offset_bytes]);
+    }
+    offsets
+}
+
+// expected memory image of an indexed store with `reversed_offsets`, bytes in
+// the gaps stay zero
+fn indexed_image(elements: &[u8], vl: usize, step: usize, sew_bytes: usize) -> Vec<u8> {
+    let mut image: Vec<u8> = Vec::new();
+    image.resize(vl * step, 0);
+    for i in 0..vl {
+        let offset = (vl - 1 - i) * step;
+        image[offset..offset + sew_bytes]
+            .copy_from_slice(&elements[i * sew_bytes..(i + 1) * sew_bytes]);
+    }
+    image
+}
+
+fn test_misaligned_unit_stride(sew: u64, misalign: usize) {
+    let sew_bytes = (sew / 8) as usize;
+    let vl = vsetvl(AVL_MAX, sew, 1) as usize;
+    let len = vl * sew_bytes;
+
+    let mut rng = BestNumberRng::default();
+    let mut src_buf: Vec<u8> = Vec::new();
+    let src = misaligned_slice(&mut src_buf, len, sew_bytes, misalign);
+    rng.fill(&mut src[..]);
+    let mut dst_buf: Vec<u8> = Vec::new();
+    let dst = misaligned_slice(&mut dst_buf, len, sew_bytes, sew_bytes - misalign);
+
+    vle_v8(sew, src);
+    vse_v8(sew, dst);
+    check("vle/vse, misaligned", sew, src.as_ptr() as usize, src, dst);
+}
+
+fn test_misaligned_stride(sew: u64, misalign: usize) {
+    let sew_bytes = (sew / 8) as usize;
+    let vl = vsetvl(AVL_MAX, sew, 1) as usize;
+    // every element is at a different misalignment
+    let stride = sew_bytes + 3;
+    let len = (vl - 1) * stride + sew_bytes;
+
+    let mut rng = BestNumberRng::default();
+    let mut src_buf: Vec<u8> = Vec::new();
+    let src = misaligned_slice(&mut src_buf, len, sew_bytes, misalign);
+    rng.fill(&mut src[..]);
+    let mut dst_buf: Vec<u8> = Vec::new();
+    let dst = misaligned_slice(&mut dst_buf, len, sew_bytes, misalign);
+
+    vlse_v8(sew, src, stride as u64);
+    vsse_v8(sew, dst, stride as u64);
+
+    let mut expected: Vec<u8> = Vec::new();
+    expected.resize(len, 0);
+    for i in 0..vl {
+        let range = i * stride..i * stride + sew_bytes;
+        expected[range.clone()].copy_from_slice(&src[range]);
+    }
+    check(
+        "vlse/vsse, misaligned",
+        sew,
+        src.as_ptr() as usize,
+        &expected,
+        dst,
+    );
+}
+
+fn test_misaligned_indexed(sew: u64, misalign: usize, ordered: bool) {
+    let sew_bytes = (sew / 8) as usize;
+    // EMUL of the offsets stays legal with LMUL = 8
+    let vl = vsetvl(16, sew, 8) as usize;
+    let offset_sew = if sew < 64 { sew } else { 64 };
+    let step = sew_bytes + 5;
+    let offsets = reversed_offsets(vl, step, offset_sew);
+
+    let mut rng = BestNumberRng::default();
+    let mut src_buf: Vec<u8> = Vec::new();
+    let src = misaligned_slice(&mut src_buf, vl * step, sew_bytes, misalign);
+    rng.fill(&mut src[..]);
+
+    let mut loaded: Vec<u8> = Vec::new();
+    loaded.resize(vl * sew_bytes, 0);
+    if ordered {
+        vloxei_v8(offset_sew, src, &offsets);
+    } else {
+        vluxei_v8(offset_sew, src, &offsets);
+    }
+    vse_v8(sew, &mut loaded);
+
+    let mut expected: Vec<u8> = Vec::new();
+    for i in 0..vl {
+        let offset = (vl - 1 - i) * step;
+        expected.extend_from_slice(&src[offset..offset + sew_bytes]);
+    }
+    check(
+        "indexed load, misaligned",
+        sew,
+        src.as_ptr() as usize,
+        &expected,
+        &loaded,
+    );
+
+    let mut dst_buf: Vec<u8> = Vec::new();
+    let dst = misaligned_slice(&mut dst_buf, vl * step, sew_bytes, misalign);
+    if ordered {
+        vsoxei_v8(offset_sew, dst, &offsets);
+    } else {
+        vsuxei_v8(offset_sew, dst, &offsets);
+    }
+    check(
+        "indexed store, misaligned",
+        sew,
+        dst.as_ptr() as usize,
+        &indexed_image(&loaded, vl, step, sew_bytes),
+        dst,
+    );
+}
+
+pub fn test_misaligned_access() {
+    for sew in [16, 32, 64, 128, 256, 512, 1024] {
+        let sew_bytes = sew as usize / 8;
+        for misalign in [1, 3, sew_bytes - 1] {
+            if misalign >= sew_bytes {
+                continue;
+            }
+            if is_verbose() {
+                log!("run with sew = {}, misalign = {}", sew, misalign);
+            }
+            test_misaligned_unit_stride(sew, misalign);
+            test_misaligned_stride(sew, misalign);
+            test_misaligned_indexed(sew, misalign, false);
+            test_misaligned_indexed(sew, misalign, true);
+        }
+    }
+}
+
+fn current_sp() -> usize {
+    let sp: usize;
+    unsafe {
+        asm!("mv {}, sp", out (reg) sp);
+    }
+    sp
+}
+
+// Accesses ending exactly at the last byte of memory, which `memory_end` takes
+// from the loader's layout. The region holds the loader's argv, which main has
+// copied, and for the larger SEWs the top of the frames calling the cases. None
+// of them is read before the region is restored.
+#[inline(never)]
+fn test_memory_end_sew(sew: u64) {
+    let end = memory_end();
+    let sew_bytes = (sew / 8) as usize;
+    let vl = vsetvl(2, sew, 8) as usize;
+    assert_eq!(vl, 2);
+    let stride = sew_bytes + 8;
+    let region_len = (vl - 1) * stride + sew_bytes;
+    let unit_len = vl * sew_bytes;
+
+    // our own frame must stay below the region
+    if current_sp() + 1024 > end - region_len {
+        log!(
+            "test_memory_end failed, stack pointer {:#X} is too close to the memory end",
+            current_sp()
+        );
+        panic!("Abort");
+    }
+
+    let region = unsafe { from_raw_parts_mut((end - region_len) as *mut u8, region_len) };
+    let saved = region.to_vec();
+
+    let mut rng = BestNumberRng::default();
+    let mut pattern: Vec<u8> = Vec::new();
+    pattern.resize(region_len, 0);
+    rng.fill(&mut pattern[..]);
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(unit_len, 0);
+
+    // unit-stride
+    region.copy_from_slice(&pattern);
+    vle_v8(sew, &region[region_len - unit_len..]);
+    vse_v8(sew, &mut result);
+    check(
+        "vle, memory end",
+        sew,
+        end - unit_len,
+        &pattern[region_len - unit_len..],
+        &result,
+    );
+    region.fill(0);
+    vse_v8(sew, &mut region[region_len - unit_len..]);
+    check(
+        "vse, memory end",
+        sew,
+        end - unit_len,
+        &pattern[region_len - unit_len..],
+        &region[region_len - unit_len..],
+    );
+
+    // strided, the last element ends at the memory end
+    region.copy_from_slice(&pattern);
+    vlse_v8(sew, region, stride as u64);
+    region.fill(0);
+    vsse_v8(sew, region, stride as u64);
+    let mut expected: Vec<u8> = Vec::new();
+    expected.resize(region_len, 0);
+    for i in 0..vl {
+        let range = i * stride..i * stride + sew_bytes;
+        expected[range.clone()].copy_from_slice(&pattern[range]);
+    }
+    check(
+        "vlse/vsse, memory end",
+        sew,
+        end - region_len,
+        &expected,
+        region,
+    );
+
+    // indexed, the first element is the last one in memory
+    let offset_sew = if sew < 64 { sew } else { 64 };
+    let offsets = reversed_offsets(vl, sew_bytes, offset_sew);
+    region.copy_from_slice(&pattern);
+    vluxei_v8(offset_sew, &region[region_len - unit_len..], &offsets);
+    vse_v8(sew, &mut result);
+    let mut expected: Vec<u8> = Vec::new();
+    for i in 0..vl {
+        let offset = region_len - (i + 1) * sew_bytes;
+        expected.extend_from_slice(&pattern[offset..offset + sew_bytes]);
+    }
+    check(
+        "vluxei, memory end",
+        sew,
+        end - unit_len,
+        &expected,
+        &result,
+    );
+    region.fill(0);
+    vsoxei_v8(offset_sew, &mut region[region_len - unit_len..], &offsets);
+    check(
+        "vsoxei, memory end",
+        sew,
+        end - unit_len,
+        &pattern[region_len - unit_len..],
+        &region[region_len - unit_len..],
+    );
+
+    region.copy_from_slice(&saved);
+}
+
+pub fn test_memory_end() {
+    for sew in [8, 16, 32, 64, 128, 256, 512, 1024] {
+        if is_verbose() {
+            log!("run with sew = {}", sew);
+        }
+        test_memory_end_sew(sew);
+    }
+}
+
+// Only with --fault=load or --fault=store. The access straddles the memory end,
+// the VM must stop with a memory out of bounds error instead of returning.
+pub fn test_memory_end_fault() {
+    let case = match get_fault_case() {
+        Some(case) => case,
+        None => return,
+    };
+    let end = memory_end();
+    let vl = vsetvl(16, 8, 1);
+    assert_eq!(vl, 16);
+    // the first 8 bytes are inside the memory, the last 8 are not
+    let p = end - 8;
+    // what the store may write: the driver (`--fault`) reads the last 8 bytes
+    // back, they must be all FAULT_MARKER or the first 8 elements of v8
+    let mut elements = [0u8; 16];
+    for (i, e) in elements.iter_mut().enumerate() {
+        *e = i as u8;
+    }
+    vle_v8(8, &elements);
+    unsafe { from_raw_parts_mut(p as *mut u8, 8) }.fill(FAULT_MARKER);
+    log!("expect a memory out of bounds error at {:#X}", end);
+    unsafe {
+        match case {
+            FaultCase::Load => {
+                rvv_asm!("mv t0, {}", "vle8.v v8, (t0)", in (reg) p);
+            }
+            FaultCase::Store => {
+                rvv_asm!("mv t0, {}", "vse8.v v8, (t0)", in (reg) p);
+            }
+        }
+    }
+    log!("test_memory_end_fault failed, the access straddling the memory end returned");
+    panic!("Abort");
+}
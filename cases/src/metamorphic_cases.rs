@@ -0,0 +1,280 @@
+use alloc::format;
+use alloc::vec::Vec;
+use ckb_std::syscalls::debug;
+use core::arch::asm;
+use rand::{Rng, RngCore};
+use rvv_asm::rvv_asm;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::digest::update_digest;
+use rvv_testcases::intrinsic::{vl1r_v0, vle_v16, vle_v8, vse_v16, vse_v24, vsetvl};
+use rvv_testcases::log;
+use rvv_testcases::misc::{get_bit_in_slice, is_full, is_verbose, VLEN};
+use rvv_testcases::rng::BestNumberRng;
+use rvv_testcases::rvv_asm_x;
+
+// Instruction sequences whose result is known without a reference model,
+// e.g. (a + b) - b == a. Only the inputs are generated on the host.
+
+// larger than any VLMAX, vsetvl returns VLMAX
+const AVL_MAX: u64 = VLEN as u64 * 8;
+
+fn random_data(rng: &mut BestNumberRng, len: usize) -> Vec<u8> {
+    let mut buf: Vec<u8> = Vec::new();
+    buf.resize(len, 0);
+    rng.fill(&mut buf[..]);
+    buf
+}
+
+fn check(desc: &str, sew: u64, lmul: i64, expected: &[u8], result: &[u8]) {
+    if expected != result {
+        let index = result.iter().zip(expected.iter()).position(|(r, e)| r != e);
+        log!(
+            "[describe = {}] identity doesn't hold at byte {:?}, sew = {}, lmul = {}",
+            desc,
+            index,
+            sew,
+            lmul
+        );
+        log!("-expected: {:0>2X?}", expected);
+        log!("-result: {:0>2X?}", result);
+        panic!("Abort");
+    }
+}
+
+fn record(insns: &[&str], sew: u64, lmul: i64) {
+    for insn in insns {
+        record_instruction(insn, sew, lmul, false);
+    }
+}
+
+// (a + b) - b == a
+fn add_sub(sew: u64, lmul: i64, vl: usize) {
+    let mut rng = BestNumberRng::default();
+    let len = vl * sew as usize / 8;
+    let a = random_data(&mut rng, len);
+    let b = random_data(&mut rng, len);
+    vle_v8(sew, &a);
+    vle_v16(sew, &b);
+    unsafe {
+        rvv_asm!("vadd.vv v24, v8, v16", "vsub.vv v24, v24, v16");
+    }
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(len, 0);
+    vse_v24(sew, &mut result);
+    check("vadd.vv, vsub.vv", sew, lmul, &a, &result);
+    record(&["vadd.vv", "vsub.vv"], sew, lmul);
+}
+
+// a * ((vid + 1) - vid) == a
+fn mul_one(sew: u64, lmul: i64, vl: usize) {
+    let mut rng = BestNumberRng::default();
+    let len = vl * sew as usize / 8;
+    let a = random_data(&mut rng, len);
+    vle_v8(sew, &a);
+    unsafe {
+        rvv_asm!(
+            "vid.v v16",
+            "vadd.vi v24, v16, 1",
+            "vsub.vv v16, v24, v16",
+            "vmul.vv v24, v8, v16"
+        );
+    }
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(len, 0);
+    vse_v24(sew, &mut result);
+    check("vid.v, vmul.vv", sew, lmul, &a, &result);
+    record(&["vid.v", "vadd.vi", "vsub.vv", "vmul.vv"], sew, lmul);
+}
+
+// gathering with vid as indices is the identity, vid must not wrap
+fn gather_identity(sew: u64, lmul: i64, vl: usize) {
+    let mut rng = BestNumberRng::default();
+    let len = vl * sew as usize / 8;
+    let a = random_data(&mut rng, len);
+    vle_v8(sew, &a);
+    unsafe {
+        rvv_asm!("vid.v v16", "vrgather.vv v24, v8, v16");
+    }
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(len, 0);
+    vse_v24(sew, &mut result);
+    check("vid.v, vrgather.vv", sew, lmul, &a, &result);
+    record(&["vid.v", "vrgather.vv"], sew, lmul);
+}
+
+// sliding up then down by the same offset restores the first vl - offset
+// elements
+fn slide_round_trip(sew: u64, lmul: i64, vl: usize) {
+    let mut rng = BestNumberRng::default();
+    let sew_bytes = sew as usize / 8;
+    let len = vl * sew_bytes;
+    let a = random_data(&mut rng, len);
+    let offset = (rng.next_u64() % vl as u64) as usize;
+    vle_v8(sew, &a);
+    unsafe {
+        rvv_asm_x!("vslideup.vx v24, v8, t0", offset as u64);
+        rvv_asm_x!("vslidedown.vx v16, v24, t0", offset as u64);
+    }
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(len, 0);
+    vse_v16(sew, &mut result);
+    let kept = (vl - offset) * sew_bytes;
+    check(
+        "vslideup.vx, vslidedown.vx",
+        sew,
+        lmul,
+        &a[..kept],
+        &result[..kept],
+    );
+    record(&["vslideup.vx", "vslidedown.vx"], sew, lmul);
+}
+
+// narrowing a sign or zero extended value by 0 bits gives it back
+fn extend_narrow(sew: u64, lmul: i64, half_lmul: i64) {
+    let vl = vsetvl(AVL_MAX, sew, half_lmul) as usize;
+    if vl == 0 || vsetvl(AVL_MAX, sew * 2, lmul) as usize != vl {
+        return;
+    }
+    let len = vl * sew as usize / 8;
+    let mut rng = BestNumberRng::default();
+    for signed in [true, false] {
+        let a = random_data(&mut rng, len);
+        vsetvl(AVL_MAX, sew, half_lmul);
+        vle_v8(sew, &a);
+        vsetvl(AVL_MAX, sew * 2, lmul);
+        unsafe {
+            if signed {
+                rvv_asm!("vsext.vf2 v24, v8");
+            } else {
+                rvv_asm!("vzext.vf2 v24, v8");
+            }
+        }
+        vsetvl(AVL_MAX, sew, half_lmul);
+        unsafe {
+            rvv_asm!("vnsrl.wi v16, v24, 0");
+        }
+        let mut result: Vec<u8> = Vec::new();
+        result.resize(len, 0);
+        vse_v16(sew, &mut result);
+        let desc = if signed {
+            "vsext.vf2, vnsrl.wi"
+        } else {
+            "vzext.vf2, vnsrl.wi"
+        };
+        check(desc, sew, half_lmul, &a, &result);
+    }
+    record(&["vsext.vf2", "vzext.vf2"], sew * 2, lmul);
+    record(&["vnsrl.wi"], sew, half_lmul);
+}
+
+// vcompress is a masked scatter to the viota positions
+fn compress_scatter(sew: u64, lmul: i64, vl: usize) {
+    let mut rng = BestNumberRng::default();
+    let sew_bytes = sew as usize / 8;
+    let len = vl * sew_bytes;
+    let a = random_data(&mut rng, len);
+    let mask = random_data(&mut rng, VLEN / 8);
+    vl1r_v0(&mask);
+    vle_v8(sew, &a);
+    unsafe {
+        rvv_asm!("vcompress.vm v24, v8, v0", "viota.m v16, v0");
+        // element index to byte offset
+        match sew {
+            8 => {}
+            16 => {
+                rvv_asm!("vsll.vi v16, v16, 1");
+            }
+            32 => {
+                rvv_asm!("vsll.vi v16, v16, 2");
+            }
+            64 => {
+                rvv_asm!("vsll.vi v16, v16, 3");
+            }
+            _ => panic!("Abort"),
+        }
+    }
+    let mut compressed: Vec<u8> = Vec::new();
+    compressed.resize(len, 0);
+    vse_v24(sew, &mut compressed);
+
+    let mut scattered: Vec<u8> = Vec::new();
+    scattered.resize(len, 0);
+    let p = scattered.as_mut_ptr();
+    unsafe {
+        match sew {
+            8 => {
+                rvv_asm!("mv t0, {}", "vsuxei8.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            16 => {
+                rvv_asm!("mv t0, {}", "vsuxei16.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            32 => {
+                rvv_asm!("mv t0, {}", "vsuxei32.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            64 => {
+                rvv_asm!("mv t0, {}", "vsuxei64.v v8, (t0), v16, v0.t", in (reg) p);
+            }
+            _ => panic!("Abort"),
+        }
+    }
+    update_digest(&scattered);
+
+    let count = (0..vl).filter(|i| get_bit_in_slice(&mask, *i) == 1).count();
+    let packed = count * sew_bytes;
+    check(
+        "vcompress.vm, viota.m + vsuxei",
+        sew,
+        lmul,
+        &scattered[..packed],
+        &compressed[..packed],
+    );
+    record(&["vcompress.vm", "viota.m", "vsll.vi"], sew, lmul);
+    record_instruction(&format!("vsuxei{}.v", sew), sew, lmul, true);
+}
+
+pub fn test_metamorphic() {
+    let sews = if is_full() {
+        [8, 16, 32, 64, 128, 256, 512, 1024].to_vec()
+    } else {
+        [8, 64, 256, 1024].to_vec()
+    };
+    let lmuls = if is_full() {
+        [-8, -4, -2, 1, 2, 4, 8].to_vec()
+    } else {
+        [-2, 1, 8].to_vec()
+    };
+
+    for sew in sews.clone() {
+        for lmul in lmuls.clone() {
+            let vl = vsetvl(AVL_MAX, sew, lmul) as usize;
+            if vl == 0 {
+                continue;
+            }
+            if is_verbose() {
+                log!("run with sew = {}, lmul = {}, vl = {}", sew, lmul, vl);
+            }
+            add_sub(sew, lmul, vl);
+            mul_one(sew, lmul, vl);
+            slide_round_trip(sew, lmul, vl);
+            // indices up to vl - 1 must fit in SEW bits
+            let vl = if sew == 8 {
+                vsetvl(256, sew, lmul) as usize
+            } else {
+                vl
+            };
+            gather_identity(sew, lmul, vl);
+            if sew <= 64 {
+                compress_scatter(sew, lmul, vl);
+            }
+        }
+    }
+
+    for sew in sews {
+        if sew == 1024 {
+            continue;
+        }
+        for (lmul, half_lmul) in [(8, 4), (4, 2), (2, 1), (1, -2), (-2, -4), (-4, -8)] {
+            extend_narrow(sew, lmul, half_lmul);
+        }
+    }
+}
@@ -1,11 +1,27 @@
+use core::cmp::Ordering;
 use core::convert::TryInto;
+use core::fmt::{Display, Formatter, Result};
 
+use alloc::format;
+use alloc::string::String;
 use alloc::vec;
 use alloc::vec::Vec;
 use eint::{Eint, E1024, E128, E16, E2048, E256, E32, E512, E64, E8};
+use rand::RngCore;
+
+use super::digest::{begin_digest, finish_digest};
+use super::rng::BestNumberRng;
+use ckb_std::syscalls::debug;
 
 static mut VERBOSE: bool = false;
 static mut RUN_FILL_CASE: bool = false;
+static mut COVERAGE_MODE: bool = false;
+static mut LIST_CASES: bool = false;
+static mut EXACT_CASE: bool = false;
+static mut DIGEST_MODE: bool = false;
+static mut AVL_STRATEGY: AvlStrategy = AvlStrategy::Boundary;
+static mut FAULT_CASE: Option<FaultCase> = None;
+static mut MEMORY_END: usize = MEMORY_SIZE;
 
 pub fn create_vtype(sew: u64, lmul: i64) -> u64 {
     let lmul_bits = match lmul {
@@ -55,6 +71,12 @@ pub fn log2(n: usize) -> usize {
 
 pub const VLEN: usize = 2048;
 
+// Addressable memory of ckb-vm, the stack starts from the top. Assumes
+// RISCV_MAX_MEMORY = 4MB, which ckb-debugger and the driver both use. The cases
+// touching the end of memory use `memory_end`, taken from where the loader put
+// argv, this is only its default.
+pub const MEMORY_SIZE: usize = 4 * 1024 * 1024;
+
 #[macro_export]
 macro_rules! log {
     ($fmt:literal) => {
@@ -67,17 +89,84 @@ macro_rules! log {
 
 #[macro_export]
 macro_rules! test_case {
-    ($fun:path, $test_pattern:ident) => {{
-        let fun_name = stringify!($fun);
-        if $test_pattern.is_none() || fun_name.contains($test_pattern.unwrap()) {
-            log!("test {} ...", fun_name);
-            $fun();
-            log!("test {}, OK", fun_name);
+    ($fun:path, $test_pattern:ident) => {
+        $crate::misc::run_case(stringify!($fun), $fun, $test_pattern)
+    };
+}
+
+pub fn run_case(fun_name: &str, fun: fn(), test_pattern: Option<&str>) {
+    let selected = match test_pattern {
+        None => true,
+        Some(pattern) if is_exact() => fun_name == pattern,
+        Some(pattern) => fun_name.contains(pattern),
+    };
+    if selected && is_list() {
+        log!("case {}", fun_name);
+    } else if selected {
+        log!("test {} ...", fun_name);
+        begin_digest();
+        fun();
+        if is_digest() {
+            log!("digest {} {}", fun_name, finish_digest());
+        }
+        log!("test {}, OK", fun_name);
+    }
+}
+
+// case name of an instruction registered by `rvv_insns!`, e.g. `vop_vv_cases::vadd.vv`
+pub fn case_name(module_path: &str, insn: &str) -> String {
+    let module = match module_path.split_once("::") {
+        Some((_, module)) => module,
+        None => module_path,
+    };
+    format!("{}::{}", module, insn)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum AvlStrategy {
+    // 1, VLMAX - 1, VLMAX, VLMAX + 1
+    Boundary,
+    // N AVLs drawn from 1..=3 * VLMAX
+    Random(usize),
+    // every AVL in 1..=VLMAX + 1, up to EXHAUSTIVE_SMALL_LIMIT
+    ExhaustiveSmall,
+    // AVLs that need 4 to 8 strips
+    LargeMultistrip,
+}
+
+const EXHAUSTIVE_SMALL_LIMIT: u64 = 32;
+
+impl Display for AvlStrategy {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            AvlStrategy::Boundary => write!(f, "boundary"),
+            AvlStrategy::Random(n) => write!(f, "random-{}", n),
+            AvlStrategy::ExhaustiveSmall => write!(f, "exhaustive-small"),
+            AvlStrategy::LargeMultistrip => write!(f, "large-multistrip"),
+        }
+    }
+}
+
+impl AvlStrategy {
+    pub fn parse(s: &str) -> Option<AvlStrategy> {
+        match s {
+            "boundary" => Some(AvlStrategy::Boundary),
+            "random" => Some(AvlStrategy::Random(8)),
+            "exhaustive-small" => Some(AvlStrategy::ExhaustiveSmall),
+            "large-multistrip" => Some(AvlStrategy::LargeMultistrip),
+            _ => {
+                let n = s.strip_prefix("random-")?.parse::<usize>().ok()?;
+                // random-0 would run no AVL at all
+                if n == 0 {
+                    return None;
+                }
+                Some(AvlStrategy::Random(n))
+            }
         }
-    }};
+    }
 }
 
-pub fn avl_iterator(sew: u64, lmul: i64, _: i64) -> Vec<u64> {
+pub fn avl_iterator(sew: u64, lmul: i64, strategy: AvlStrategy) -> Vec<u64> {
     let lmul = match lmul {
         -8 => 0.125,
         -4 => 0.25,
@@ -90,11 +179,26 @@ pub fn avl_iterator(sew: u64, lmul: i64, _: i64) -> Vec<u64> {
     };
 
     let ret = VLEN as f64 / sew as f64 * lmul;
-    if ret <= 1.0 {
-        Vec::<u64>::new()
-    } else {
-        let ret = ret as u64;
-        vec![ret - 1, ret + 1]
+    if ret < 1.0 {
+        return Vec::<u64>::new();
+    }
+    let vlmax = ret as u64;
+    match strategy {
+        AvlStrategy::Boundary => {
+            // VLMAX = 1 would give 0 and 1 twice, VLMAX = 2 would give 1 twice
+            let mut avls = vec![1, vlmax - 1, vlmax, vlmax + 1];
+            avls.retain(|avl| *avl > 0);
+            avls.dedup();
+            avls
+        }
+        AvlStrategy::Random(n) => {
+            let mut rng = BestNumberRng::default();
+            (0..n).map(|_| rng.next_u64() % (vlmax * 3) + 1).collect()
+        }
+        AvlStrategy::ExhaustiveSmall => {
+            (1..=core::cmp::min(vlmax + 1, EXHAUSTIVE_SMALL_LIMIT)).collect()
+        }
+        AvlStrategy::LargeMultistrip => vec![vlmax * 3 + 1, vlmax * 5 + vlmax / 2, vlmax * 8],
     }
 }
 
@@ -149,6 +253,88 @@ pub fn set_full(b: bool) {
     }
 }
 
+pub fn is_coverage() -> bool {
+    unsafe { COVERAGE_MODE }
+}
+
+pub fn set_coverage(b: bool) {
+    unsafe {
+        COVERAGE_MODE = b;
+    }
+}
+
+// print the selected cases instead of running them
+pub fn is_list() -> bool {
+    unsafe { LIST_CASES }
+}
+
+pub fn set_list(b: bool) {
+    unsafe {
+        LIST_CASES = b;
+    }
+}
+
+// --case must match the whole case name
+pub fn is_exact() -> bool {
+    unsafe { EXACT_CASE }
+}
+
+pub fn set_exact(b: bool) {
+    unsafe {
+        EXACT_CASE = b;
+    }
+}
+
+// print a digest of the outputs of each case
+pub fn is_digest() -> bool {
+    unsafe { DIGEST_MODE }
+}
+
+pub fn set_digest(b: bool) {
+    unsafe {
+        DIGEST_MODE = b;
+    }
+}
+
+pub fn get_avl_strategy() -> AvlStrategy {
+    unsafe { AVL_STRATEGY }
+}
+
+pub fn set_avl_strategy(strategy: AvlStrategy) {
+    unsafe {
+        AVL_STRATEGY = strategy;
+    }
+}
+
+// access straddling the end of memory, the VM is expected to stop with an error
+#[derive(Clone, Copy, PartialEq)]
+pub enum FaultCase {
+    Load,
+    Store,
+}
+
+pub fn get_fault_case() -> Option<FaultCase> {
+    unsafe { FAULT_CASE }
+}
+
+pub fn set_fault_case(case: Option<FaultCase>) {
+    unsafe {
+        FAULT_CASE = case;
+    }
+}
+
+// The loader copies argv[0] first, right below the end of memory, main sets
+// this from its address
+pub fn memory_end() -> usize {
+    unsafe { MEMORY_END }
+}
+
+pub fn set_memory_end(end: usize) {
+    unsafe {
+        MEMORY_END = end;
+    }
+}
+
 pub trait SliceUtils<'a> {
     fn get_element(&'a self, sew: usize, index: usize) -> &'a [u8];
     fn read_u8(&self, eew: usize, index: usize) -> E8;
@@ -367,3 +553,459 @@ pub fn to_512(d: &[u8]) -> E512 {
 pub fn to_1024(d: &[u8]) -> E1024 {
     E1024::get(d)
 }
+
+// One element of any SEW, so a reference op can be written once and
+// instantiated per SEW (see `runner::expected_vv`). Everything is an
+// associated function, which keeps `x.wrapping_add(y)` on eint types resolving
+// to `Eint` in files importing both traits.
+pub trait RvvElement: Copy {
+    const BITS: u32;
+    // twice as wide, E2048 is its own and can't be widened
+    type Wide: RvvElement;
+
+    fn from_slice(d: &[u8]) -> Self;
+    fn to_slice(v: Self, d: &mut [u8]);
+    // a scalar operand, sign-extended to SEW
+    fn from_i64(x: i64) -> Self;
+    // a scalar operand, zero-extended to SEW
+    fn from_u64(x: u64) -> Self;
+    fn zero() -> Self;
+    fn max_u() -> Self;
+
+    fn wrapping_add(a: Self, b: Self) -> Self;
+    fn wrapping_sub(a: Self, b: Self) -> Self;
+    fn wrapping_mul(a: Self, b: Self) -> Self;
+    // RISC-V division: x / 0 is all ones, x % 0 is x and MIN / -1 is MIN
+    fn div_u(a: Self, b: Self) -> Self;
+    fn div_s(a: Self, b: Self) -> Self;
+    fn rem_u(a: Self, b: Self) -> Self;
+    fn rem_s(a: Self, b: Self) -> Self;
+    // signed fractional multiply, (a * b) >> (BITS - 1), MIN * MIN saturates
+    fn smul(a: Self, b: Self) -> Self;
+    fn overflowing_add_u(a: Self, b: Self) -> (Self, bool);
+    fn overflowing_sub_u(a: Self, b: Self) -> (Self, bool);
+    fn saturating_add_u(a: Self, b: Self) -> Self;
+    fn saturating_sub_u(a: Self, b: Self) -> Self;
+    fn saturating_add_s(a: Self, b: Self) -> Self;
+    fn saturating_sub_s(a: Self, b: Self) -> Self;
+    fn and(a: Self, b: Self) -> Self;
+    fn or(a: Self, b: Self) -> Self;
+    fn xor(a: Self, b: Self) -> Self;
+    // shift amounts are taken modulo BITS
+    fn shl(a: Self, n: u32) -> Self;
+    fn shr(a: Self, n: u32) -> Self;
+    fn sra(a: Self, n: u32) -> Self;
+    // the low 32 bits, e.g. a shift amount held in an element
+    fn low_u32(a: Self) -> u32;
+    fn cmp_u(a: Self, b: Self) -> Ordering;
+    fn cmp_s(a: Self, b: Self) -> Ordering;
+    fn is_negative(a: Self) -> bool;
+
+    fn widen_u(a: Self) -> Self::Wide;
+    fn widen_s(a: Self) -> Self::Wide;
+    // the low half
+    fn narrow(w: Self::Wide) -> Self;
+}
+
+macro_rules! rvv_element_primitive {
+    ($t:ty, $s:ty, $wide:ty, $wide_s:ty) => {
+        impl RvvElement for $t {
+            const BITS: u32 = <$t>::BITS;
+            type Wide = $wide;
+
+            fn from_slice(d: &[u8]) -> Self {
+                <$t>::from_le_bytes(d.try_into().unwrap())
+            }
+            fn to_slice(v: Self, d: &mut [u8]) {
+                d.copy_from_slice(&v.to_le_bytes());
+            }
+            fn from_i64(x: i64) -> Self {
+                x as $t
+            }
+            fn from_u64(x: u64) -> Self {
+                x as $t
+            }
+            fn zero() -> Self {
+                0
+            }
+            fn max_u() -> Self {
+                <$t>::MAX
+            }
+
+            fn wrapping_add(a: Self, b: Self) -> Self {
+                a.wrapping_add(b)
+            }
+            fn wrapping_sub(a: Self, b: Self) -> Self {
+                a.wrapping_sub(b)
+            }
+            fn wrapping_mul(a: Self, b: Self) -> Self {
+                a.wrapping_mul(b)
+            }
+            fn div_u(a: Self, b: Self) -> Self {
+                if b == 0 {
+                    <$t>::MAX
+                } else {
+                    a / b
+                }
+            }
+            fn div_s(a: Self, b: Self) -> Self {
+                if b == 0 {
+                    <$t>::MAX
+                } else {
+                    (a as $s).wrapping_div(b as $s) as $t
+                }
+            }
+            fn rem_u(a: Self, b: Self) -> Self {
+                if b == 0 {
+                    a
+                } else {
+                    a % b
+                }
+            }
+            fn rem_s(a: Self, b: Self) -> Self {
+                if b == 0 {
+                    a
+                } else {
+                    (a as $s).wrapping_rem(b as $s) as $t
+                }
+            }
+            fn smul(a: Self, b: Self) -> Self {
+                if a == b && a as $s == <$s>::MIN {
+                    <$s>::MAX as $t
+                } else {
+                    ((a as $s as $wide_s).wrapping_mul(b as $s as $wide_s) >> (<$t>::BITS - 1))
+                        as $t
+                }
+            }
+            fn overflowing_add_u(a: Self, b: Self) -> (Self, bool) {
+                a.overflowing_add(b)
+            }
+            fn overflowing_sub_u(a: Self, b: Self) -> (Self, bool) {
+                a.overflowing_sub(b)
+            }
+            fn saturating_add_u(a: Self, b: Self) -> Self {
+                a.saturating_add(b)
+            }
+            fn saturating_sub_u(a: Self, b: Self) -> Self {
+                a.saturating_sub(b)
+            }
+            fn saturating_add_s(a: Self, b: Self) -> Self {
+                (a as $s).saturating_add(b as $s) as $t
+            }
+            fn saturating_sub_s(a: Self, b: Self) -> Self {
+                (a as $s).saturating_sub(b as $s) as $t
+            }
+            fn and(a: Self, b: Self) -> Self {
+                a & b
+            }
+            fn or(a: Self, b: Self) -> Self {
+                a | b
+            }
+            fn xor(a: Self, b: Self) -> Self {
+                a ^ b
+            }
+            fn shl(a: Self, n: u32) -> Self {
+                a.wrapping_shl(n)
+            }
+            fn shr(a: Self, n: u32) -> Self {
+                a.wrapping_shr(n)
+            }
+            fn sra(a: Self, n: u32) -> Self {
+                (a as $s).wrapping_shr(n) as $t
+            }
+            fn low_u32(a: Self) -> u32 {
+                a as u32
+            }
+            fn cmp_u(a: Self, b: Self) -> Ordering {
+                a.cmp(&b)
+            }
+            fn cmp_s(a: Self, b: Self) -> Ordering {
+                (a as $s).cmp(&(b as $s))
+            }
+            fn is_negative(a: Self) -> bool {
+                (a as $s) < 0
+            }
+
+            fn widen_u(a: Self) -> Self::Wide {
+                a as $wide
+            }
+            fn widen_s(a: Self) -> Self::Wide {
+                a as $s as $wide_s as $wide
+            }
+            fn narrow(w: Self::Wide) -> Self {
+                w as $t
+            }
+        }
+    };
+}
+
+rvv_element_primitive!(u8, i8, u16, i16);
+rvv_element_primitive!(u16, i16, u32, i32);
+rvv_element_primitive!(u32, i32, u64, i64);
+rvv_element_primitive!(u64, i64, u128, i128);
+
+macro_rules! rvv_element_eint {
+    ($t:ident, $bits:expr, $wide:ident, |$a:ident| $widen_s:expr, |$w:ident| $narrow:expr) => {
+        impl RvvElement for $t {
+            const BITS: u32 = $bits;
+            type Wide = $wide;
+
+            fn from_slice(d: &[u8]) -> Self {
+                $t::get(d)
+            }
+            fn to_slice(v: Self, d: &mut [u8]) {
+                v.put(d);
+            }
+            fn from_i64(x: i64) -> Self {
+                $t::from(x)
+            }
+            fn from_u64(x: u64) -> Self {
+                $t::from(x)
+            }
+            fn zero() -> Self {
+                $t::from(0u64)
+            }
+            fn max_u() -> Self {
+                $t::MAX_U
+            }
+
+            fn wrapping_add(a: Self, b: Self) -> Self {
+                a.wrapping_add(b)
+            }
+            fn wrapping_sub(a: Self, b: Self) -> Self {
+                a.wrapping_sub(b)
+            }
+            fn wrapping_mul(a: Self, b: Self) -> Self {
+                a.wrapping_mul(b)
+            }
+            fn div_u(a: Self, b: Self) -> Self {
+                a.wrapping_div_u(b)
+            }
+            fn div_s(a: Self, b: Self) -> Self {
+                a.wrapping_div_s(b)
+            }
+            fn rem_u(a: Self, b: Self) -> Self {
+                a.wrapping_rem_u(b)
+            }
+            fn rem_s(a: Self, b: Self) -> Self {
+                a.wrapping_rem_s(b)
+            }
+            fn smul(a: Self, b: Self) -> Self {
+                if a == b && a == $t::MIN_S {
+                    $t::MAX_S
+                } else {
+                    let (lo, hi) = a.widening_mul_s(b);
+                    lo.wrapping_shr($bits - 1) | hi.wrapping_shl(1)
+                }
+            }
+            fn overflowing_add_u(a: Self, b: Self) -> (Self, bool) {
+                a.overflowing_add_u(b)
+            }
+            fn overflowing_sub_u(a: Self, b: Self) -> (Self, bool) {
+                a.overflowing_sub_u(b)
+            }
+            fn saturating_add_u(a: Self, b: Self) -> Self {
+                match a.overflowing_add_u(b) {
+                    (_, true) => $t::MAX_U,
+                    (r, false) => r,
+                }
+            }
+            fn saturating_sub_u(a: Self, b: Self) -> Self {
+                match a.overflowing_sub_u(b) {
+                    (_, true) => $t::from(0u64),
+                    (r, false) => r,
+                }
+            }
+            fn saturating_add_s(a: Self, b: Self) -> Self {
+                a.saturating_add_s(b).0
+            }
+            fn saturating_sub_s(a: Self, b: Self) -> Self {
+                a.saturating_sub_s(b).0
+            }
+            fn and(a: Self, b: Self) -> Self {
+                a & b
+            }
+            fn or(a: Self, b: Self) -> Self {
+                a | b
+            }
+            fn xor(a: Self, b: Self) -> Self {
+                a ^ b
+            }
+            fn shl(a: Self, n: u32) -> Self {
+                a.wrapping_shl(n % $bits)
+            }
+            fn shr(a: Self, n: u32) -> Self {
+                a.wrapping_shr(n % $bits)
+            }
+            fn sra(a: Self, n: u32) -> Self {
+                a.wrapping_sra(n % $bits)
+            }
+            fn low_u32(a: Self) -> u32 {
+                a.u32()
+            }
+            fn cmp_u(a: Self, b: Self) -> Ordering {
+                a.cmp_u(&b)
+            }
+            fn cmp_s(a: Self, b: Self) -> Ordering {
+                a.cmp_s(&b)
+            }
+            fn is_negative(a: Self) -> bool {
+                a.is_negative()
+            }
+
+            fn widen_u(a: Self) -> Self::Wide {
+                $wide::from(a)
+            }
+            fn widen_s($a: Self) -> Self::Wide {
+                $widen_s
+            }
+            fn narrow($w: Self::Wide) -> Self {
+                $narrow
+            }
+        }
+    };
+}
+
+rvv_element_eint!(E256, 256, E512, |a| conver_to_i512(a), |w| w.0);
+rvv_element_eint!(E512, 512, E1024, |a| conver_to_i1024(a), |w| w.0);
+rvv_element_eint!(E1024, 1024, E2048, |a| conver_to_i2048(a), |w| w.0);
+rvv_element_eint!(
+    E2048,
+    2048,
+    E2048,
+    |_a| panic!("E2048 can't be widened"),
+    |w| w
+);
+
+impl RvvElement for u128 {
+    const BITS: u32 = u128::BITS;
+    type Wide = E256;
+
+    fn from_slice(d: &[u8]) -> Self {
+        to_u128(d)
+    }
+    fn to_slice(v: Self, d: &mut [u8]) {
+        d.copy_from_slice(&v.to_le_bytes());
+    }
+    fn from_i64(x: i64) -> Self {
+        x as u128
+    }
+    fn from_u64(x: u64) -> Self {
+        x as u128
+    }
+    fn zero() -> Self {
+        0
+    }
+    fn max_u() -> Self {
+        u128::MAX
+    }
+
+    fn wrapping_add(a: Self, b: Self) -> Self {
+        a.wrapping_add(b)
+    }
+    fn wrapping_sub(a: Self, b: Self) -> Self {
+        a.wrapping_sub(b)
+    }
+    fn wrapping_mul(a: Self, b: Self) -> Self {
+        a.wrapping_mul(b)
+    }
+    fn div_u(a: Self, b: Self) -> Self {
+        if b == 0 {
+            u128::MAX
+        } else {
+            a / b
+        }
+    }
+    fn div_s(a: Self, b: Self) -> Self {
+        if b == 0 {
+            u128::MAX
+        } else {
+            (a as i128).wrapping_div(b as i128) as u128
+        }
+    }
+    fn rem_u(a: Self, b: Self) -> Self {
+        if b == 0 {
+            a
+        } else {
+            a % b
+        }
+    }
+    fn rem_s(a: Self, b: Self) -> Self {
+        if b == 0 {
+            a
+        } else {
+            (a as i128).wrapping_rem(b as i128) as u128
+        }
+    }
+    fn smul(a: Self, b: Self) -> Self {
+        if a == b && a as i128 == i128::MIN {
+            i128::MAX as u128
+        } else {
+            let (lo, hi) = E128::get(&a.to_le_bytes()).widening_mul_s(E128::get(&b.to_le_bytes()));
+            let mut buf = [0u8; 16];
+            (lo.wrapping_shr(127) | hi.wrapping_shl(1)).put(&mut buf);
+            u128::from_le_bytes(buf)
+        }
+    }
+    fn overflowing_add_u(a: Self, b: Self) -> (Self, bool) {
+        a.overflowing_add(b)
+    }
+    fn overflowing_sub_u(a: Self, b: Self) -> (Self, bool) {
+        a.overflowing_sub(b)
+    }
+    fn saturating_add_u(a: Self, b: Self) -> Self {
+        a.saturating_add(b)
+    }
+    fn saturating_sub_u(a: Self, b: Self) -> Self {
+        a.saturating_sub(b)
+    }
+    fn saturating_add_s(a: Self, b: Self) -> Self {
+        (a as i128).saturating_add(b as i128) as u128
+    }
+    fn saturating_sub_s(a: Self, b: Self) -> Self {
+        (a as i128).saturating_sub(b as i128) as u128
+    }
+    fn and(a: Self, b: Self) -> Self {
+        a & b
+    }
+    fn or(a: Self, b: Self) -> Self {
+        a | b
+    }
+    fn xor(a: Self, b: Self) -> Self {
+        a ^ b
+    }
+    fn shl(a: Self, n: u32) -> Self {
+        a.wrapping_shl(n)
+    }
+    fn shr(a: Self, n: u32) -> Self {
+        a.wrapping_shr(n)
+    }
+    fn sra(a: Self, n: u32) -> Self {
+        (a as i128).wrapping_shr(n) as u128
+    }
+    fn low_u32(a: Self) -> u32 {
+        a as u32
+    }
+    fn cmp_u(a: Self, b: Self) -> Ordering {
+        a.cmp(&b)
+    }
+    fn cmp_s(a: Self, b: Self) -> Ordering {
+        (a as i128).cmp(&(b as i128))
+    }
+    fn is_negative(a: Self) -> bool {
+        (a as i128) < 0
+    }
+
+    // no primitive is wide enough, go through eint
+    fn widen_u(a: Self) -> Self::Wide {
+        E256::from(E128::get(&a.to_le_bytes()))
+    }
+    fn widen_s(a: Self) -> Self::Wide {
+        conver_to_i256(E128::get(&a.to_le_bytes()))
+    }
+    fn narrow(w: Self::Wide) -> Self {
+        let mut buf = [0u8; 16];
+        w.0.put(&mut buf);
+        u128::from_le_bytes(buf)
+    }
+}
@@ -0,0 +1,281 @@
+use alloc::vec::Vec;
+use ckb_std::syscalls::debug;
+use core::arch::asm;
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+use rand::Rng;
+use rvv_asm::rvv_asm;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::intrinsic::{vle_v16, vle_v8, vse_v24, vsetvl};
+use rvv_testcases::log;
+use rvv_testcases::misc::{is_verbose, VLEN};
+use rvv_testcases::rng::BestNumberRng;
+
+const SECP256K1_P: &[u8] = b"FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F";
+const BN254_P: &[u8] = b"30644E72E131A029B85045B68181585D97816A916871CA8D3C208C16D87CFD47";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kernel {
+    // a * b * R^-1 mod p
+    MontMul,
+    // a + b mod p
+    ModAdd,
+    // a mod p, a is any SEW-bit number
+    Reduce,
+}
+
+struct Field {
+    name: &'static str,
+    p: BigUint,
+}
+
+// -p^-1 mod r, every Newton step doubles the number of correct bits
+fn n_prime(p: &BigUint, r: &BigUint) -> BigUint {
+    let two = BigUint::from(2u32);
+    let mut inv = BigUint::one();
+    for _ in 0..10 {
+        let t = (p * &inv) % r;
+        inv = (&inv * ((&two + r - t) % r)) % r;
+    }
+    (r - inv) % r
+}
+
+fn to_element(n: &BigUint, sew_bytes: usize) -> Vec<u8> {
+    let mut bytes = n.to_bytes_le();
+    assert!(bytes.len() <= sew_bytes);
+    bytes.resize(sew_bytes, 0);
+    bytes
+}
+
+// p is broadcast to v1 and n' to v2 with zero-strided loads
+fn load_constants(sew: u64, p: &[u8], n_prime: &[u8]) {
+    let stride = 0u64;
+    unsafe {
+        match sew {
+            256 => {
+                rvv_asm!("mv t0, {0}", "mv t1, {1}", "vlse256.v v1, (t0), t1", in (reg) p.as_ptr(), in (reg) stride);
+                rvv_asm!("mv t0, {0}", "mv t1, {1}", "vlse256.v v2, (t0), t1", in (reg) n_prime.as_ptr(), in (reg) stride);
+            }
+            512 => {
+                rvv_asm!("mv t0, {0}", "mv t1, {1}", "vlse512.v v1, (t0), t1", in (reg) p.as_ptr(), in (reg) stride);
+                rvv_asm!("mv t0, {0}", "mv t1, {1}", "vlse512.v v2, (t0), t1", in (reg) n_prime.as_ptr(), in (reg) stride);
+            }
+            _ => panic!("Abort"),
+        }
+    }
+}
+
+// v24 = v8 * v16 * R^-1 mod v1, with v8, v16 < p.
+// (T + m * p) / R < 2p may not fit in SEW bits, the carry out in v3 forces the
+// final subtraction.
+fn mont_mul() {
+    unsafe {
+        rvv_asm!(
+            "vmul.vv v10, v8, v16",
+            "vmulhu.vv v11, v8, v16",
+            "vmul.vv v12, v10, v2",
+            "vmul.vv v13, v12, v1",
+            "vmulhu.vv v14, v12, v1",
+            "vmadc.vv v0, v10, v13",
+            "vadc.vvm v15, v11, v14, v0",
+            "vmadc.vvm v3, v11, v14, v0",
+            "vmsltu.vv v4, v15, v1",
+            "vmandnot.mm v0, v4, v3",
+            "vsub.vv v5, v15, v1",
+            "vmerge.vvm v24, v5, v15, v0"
+        );
+    }
+}
+
+// v24 = v8 + v16 mod v1, with v8, v16 < p
+fn mod_add() {
+    unsafe {
+        rvv_asm!(
+            "vadd.vv v10, v8, v16",
+            "vmadc.vv v3, v8, v16",
+            "vmsltu.vv v4, v10, v1",
+            "vmandnot.mm v0, v4, v3",
+            "vsub.vv v5, v10, v1",
+            "vmerge.vvm v24, v5, v10, v0"
+        );
+    }
+}
+
+// v24 = v8 mod v1, one conditional subtraction per round
+fn reduce(rounds: usize) {
+    unsafe {
+        rvv_asm!("vmv.v.v v24, v8");
+    }
+    for _ in 0..rounds {
+        unsafe {
+            rvv_asm!(
+                "vmsltu.vv v0, v24, v1",
+                "vsub.vv v5, v24, v1",
+                "vmerge.vvm v24, v5, v24, v0"
+            );
+        }
+    }
+}
+
+fn check(
+    kernel: Kernel,
+    field: &Field,
+    sew: u64,
+    index: usize,
+    a: &BigUint,
+    b: &BigUint,
+    result: &BigUint,
+) {
+    let p = &field.p;
+    let passed = match kernel {
+        Kernel::MontMul => {
+            let r = BigUint::one() << sew as usize;
+            result < p && (result * r) % p == (a * b) % p
+        }
+        Kernel::ModAdd => *result == (a + b) % p,
+        Kernel::Reduce => *result == a % p,
+    };
+    if !passed {
+        let desc = match kernel {
+            Kernel::MontMul => "montgomery multiplication",
+            Kernel::ModAdd => "modular addition",
+            Kernel::Reduce => "reduction",
+        };
+        log!(
+            "[describe = {}] unexpected value found at element {}, field = {}, sew = {}",
+            desc,
+            index,
+            field.name,
+            sew
+        );
+        log!("-a: {:X}", a);
+        log!("-b: {:X}", b);
+        log!("-result: {:X}", result);
+        panic!("Abort");
+    }
+}
+
+fn run(kernel: Kernel, field: &Field, sew: u64) {
+    let sew_bytes = (sew / 8) as usize;
+    let r = BigUint::one() << sew as usize;
+    let p = &field.p;
+    // at most R / p subtractions
+    let rounds = (&r - 1u32) / p;
+    if kernel == Kernel::Reduce && rounds > BigUint::from(8u32) {
+        return;
+    }
+    let rounds = rounds.to_usize().unwrap_or(0);
+    let vl = vsetvl(VLEN as u64, sew, 1) as usize;
+    if is_verbose() {
+        log!(
+            "run with field = {}, sew = {}, vl = {}",
+            field.name,
+            sew,
+            vl
+        );
+    }
+
+    let mut rng = BestNumberRng::default();
+    let mut random = [0u8; 64];
+    let mut a: Vec<BigUint> = Vec::new();
+    let mut b: Vec<BigUint> = Vec::new();
+    for i in 0..vl {
+        rng.fill(&mut random[..sew_bytes]);
+        let x = BigUint::from_bytes_le(&random[..sew_bytes]);
+        rng.fill(&mut random[..sew_bytes]);
+        let y = BigUint::from_bytes_le(&random[..sew_bytes]);
+        let (x, y) = match i {
+            // the largest values, the intermediate results overflow SEW
+            0 => (p - 1u32, p - 1u32),
+            1 => (BigUint::zero(), p - 1u32),
+            // R mod p is one in Montgomery form
+            2 => (BigUint::one(), &r % p),
+            _ => (x, y),
+        };
+        if kernel == Kernel::Reduce {
+            a.push(if i == 0 { &r - 1u32 } else { x });
+            b.push(BigUint::zero());
+        } else {
+            a.push(x % p);
+            b.push(y % p);
+        }
+    }
+
+    let mut lhs: Vec<u8> = Vec::new();
+    let mut rhs: Vec<u8> = Vec::new();
+    for i in 0..vl {
+        lhs.extend_from_slice(&to_element(&a[i], sew_bytes));
+        rhs.extend_from_slice(&to_element(&b[i], sew_bytes));
+    }
+    vle_v8(sew, &lhs);
+    vle_v16(sew, &rhs);
+    load_constants(
+        sew,
+        &to_element(p, sew_bytes),
+        &to_element(&n_prime(p, &r), sew_bytes),
+    );
+    match kernel {
+        Kernel::MontMul => mont_mul(),
+        Kernel::ModAdd => mod_add(),
+        Kernel::Reduce => reduce(rounds),
+    }
+    let mut result: Vec<u8> = Vec::new();
+    result.resize(vl * sew_bytes, 0);
+    vse_v24(sew, &mut result);
+
+    for i in 0..vl {
+        let res = BigUint::from_bytes_le(&result[i * sew_bytes..(i + 1) * sew_bytes]);
+        check(kernel, field, sew, i, &a[i], &b[i], &res);
+    }
+
+    let insns: &[&str] = match kernel {
+        Kernel::MontMul => &[
+            "vmul.vv",
+            "vmulhu.vv",
+            "vmadc.vv",
+            "vadc.vvm",
+            "vmadc.vvm",
+            "vmsltu.vv",
+            "vmandnot.mm",
+            "vsub.vv",
+            "vmerge.vvm",
+        ],
+        Kernel::ModAdd => &[
+            "vadd.vv",
+            "vmadc.vv",
+            "vmsltu.vv",
+            "vmandnot.mm",
+            "vsub.vv",
+            "vmerge.vvm",
+        ],
+        Kernel::Reduce => &["vmv.v.v", "vmsltu.vv", "vsub.vv", "vmerge.vvm"],
+    };
+    for insn in insns {
+        record_instruction(insn, sew, 1, false);
+    }
+}
+
+pub fn test_montgomery() {
+    let secp256k1 = Field {
+        name: "secp256k1",
+        p: BigUint::parse_bytes(SECP256K1_P, 16).unwrap(),
+    };
+    let bn254 = Field {
+        name: "bn254",
+        p: BigUint::parse_bytes(BN254_P, 16).unwrap(),
+    };
+    let p512 = Field {
+        name: "2^512 - 569",
+        p: (BigUint::one() << 512usize) - 569u32,
+    };
+    for (sew, field) in [
+        (256, &secp256k1),
+        (256, &bn254),
+        (512, &p512),
+        (512, &bn254),
+    ] {
+        for kernel in [Kernel::MontMul, Kernel::ModAdd, Kernel::Reduce] {
+            run(kernel, field, sew);
+        }
+    }
+}
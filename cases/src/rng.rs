@@ -27,6 +27,14 @@ pub fn get_seed() -> u64 {
     unsafe { SEED }
 }
 
+// The seed of iteration `iteration` of an `--iterations` run, splitmix64
+pub fn iteration_seed(master_seed: u64, iteration: u64) -> u64 {
+    let mut z = master_seed.wrapping_add((iteration + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
 pub struct BestNumberRngSeed(pub [u8; 1024]);
 
 impl Default for BestNumberRngSeed {
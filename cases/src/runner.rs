@@ -1,19 +1,24 @@
 use alloc::boxed::Box;
 use alloc::vec::Vec;
+use core::arch::asm;
 use core::convert::TryInto;
 use core::fmt::{Display, Formatter, Result};
 use core::ops::Range;
 
 use ckb_std::syscalls::debug;
+use eint::{E1024, E256, E512};
 use rand::Rng;
 
+use crate::coverage::record_instruction;
+use crate::digest::{begin_config_digest, finish_config_digest, update_digest};
 use crate::intrinsic::{
-    clean_cache_v16, clean_cache_v8, vl1r_v0, vle_v16, vle_v24, vle_v8, vse_v24, vsetvl,
+    clean_cache_v8, vl1r_v0, vl8r_all, vl8r_v16, vl8r_v24, vl8r_v8, vle_v16, vle_v24, vle_v8,
+    vs8r_all, vs8r_v24, vse_v24, vsetvl,
 };
-use crate::misc::{avl_iterator, VLEN};
+use crate::misc::{avl_iterator, get_avl_strategy, VLEN};
 
 use super::log;
-use super::misc::{get_bit_in_slice, is_full, is_verbose, set_bit_in_slice};
+use super::misc::{get_bit_in_slice, is_digest, is_full, is_verbose, set_bit_in_slice, RvvElement};
 use super::rng::BestNumberRng;
 
 pub enum WideningCategory {
@@ -47,34 +52,39 @@ where
     rng.fill_mask(&mut mask_v0[..]);
     rng.fill(&mut vs2[..]);
 
-    let vl = vsetvl(8, 256, 1) as usize;
-    assert_eq!(vl, 8);
+    // vl = 0: vcpop.m must return 0 and vfirst.m must return -1
+    for avl in [8, 0] {
+        let vl = vsetvl(avl, 256, 1) as usize;
+        assert_eq!(vl, avl as usize);
 
-    let expected = if let ExpectedOp::EnableMask(ref mut op) = expected_op {
-        let mut temp = [0u8; 8];
-        op(&mask_v0[..], &vs2[..], &mut temp[..], enable_mask, vl);
-        u64::from_le_bytes(temp)
-    } else {
-        panic!("Unexpected op")
-    };
-
-    let mut temp = [0u8; 8];
-    v_op(
-        mask_v0.as_slice(),
-        vs2.as_slice(),
-        temp.as_mut_slice(),
-        enable_mask,
-    );
-    let result = u64::from_le_bytes(temp);
+        let expected = if let ExpectedOp::EnableMask(ref mut op) = expected_op {
+            let mut temp = [0u8; 8];
+            op(&mask_v0[..], &vs2[..], &mut temp[..], enable_mask, vl);
+            u64::from_le_bytes(temp)
+        } else {
+            panic!("Unexpected op")
+        };
 
-    if result != expected {
-        log!(
-            "[describe = {}] unexpected values found: {:?} (result) {:?} (expected)",
-            desc,
-            result,
-            expected
+        let mut temp = [0u8; 8];
+        v_op(
+            mask_v0.as_slice(),
+            vs2.as_slice(),
+            temp.as_mut_slice(),
+            enable_mask,
         );
-        panic!("Abort");
+        let result = u64::from_le_bytes(temp);
+        record_instruction(desc, 256, 1, enable_mask);
+
+        if result != expected {
+            log!(
+                "[describe = {}, vl = {}] unexpected values found: {:?} (result) {:?} (expected)",
+                desc,
+                vl,
+                result,
+                expected
+            );
+            panic!("Abort");
+        }
     }
     if is_verbose() {
         log!("finished");
@@ -157,6 +167,21 @@ impl Display for MaskType {
     }
 }
 
+// The inputs of an op, see `shrink`
+#[derive(Clone, Copy)]
+enum Operand {
+    Lhs,
+    Rhs,
+    ResultBefore,
+}
+
+#[derive(Clone, Copy)]
+enum SimpleValue {
+    Zero,
+    One,
+    Max,
+}
+
 pub struct RVVTestData {
     pub lhs: Vec<u8>,
     pub lhs_type: InstructionArgsType,
@@ -176,9 +201,13 @@ pub struct RVVTestData {
     pub sew: u64,
     pub lmul: i64,
     pub avl: u64,
+    // all AVLs chosen for this sew/lmul
+    pub avl_list: Vec<u64>,
 
     pub theoretically_vl: usize,
     pub count: usize,
+    // vs2 is zero past vl instead of poisoned, for ops reading it up to VLMAX
+    pub zero_vs2_tail: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -238,9 +267,11 @@ impl RVVTestData {
             sew,
             lmul,
             avl,
+            avl_list: Vec::new(),
             theoretically_vl: vl as usize,
 
             count: 0,
+            zero_vs2_tail: false,
         }
     }
 
@@ -285,6 +316,133 @@ impl RVVTestData {
         self.res_exp = self.res_before.clone();
     }
 
+    // A copy holding the first `avl` elements, vl follows from the new avl
+    fn truncated(&self, avl: u64) -> RVVTestData {
+        fn prefix(d: &[u8], t: InstructionArgsType, sew_bytes: usize, avl: u64) -> Vec<u8> {
+            match t {
+                InstructionArgsType::Scalar
+                | InstructionArgsType::Immediate
+                | InstructionArgsType::UImmediate
+                | InstructionArgsType::None => d.to_vec(),
+                _ => d[..t.get_buf_len(sew_bytes, avl as usize)].to_vec(),
+            }
+        }
+        let mut data = RVVTestData::new(
+            self.lhs_type,
+            self.rhs_type,
+            self.res_type,
+            self.mask_type,
+            self.sew,
+            self.lmul,
+            avl,
+        );
+        data.avl_list = self.avl_list.clone();
+        data.lhs = prefix(&self.lhs, self.lhs_type, self.sew_bytes, avl);
+        data.rhs = prefix(&self.rhs, self.rhs_type, self.sew_bytes, avl);
+        data.res_before = prefix(&self.res_before, self.res_type, self.sew_bytes, avl);
+        data.res_rvv = data.res_before.clone();
+        data.res_exp = data.res_before.clone();
+        data.mask = self.mask.clone();
+        data.zero_vs2_tail = self.zero_vs2_tail;
+        data
+    }
+
+    // Byte range of element `index` in an operand of type `t`, None for
+    // scalars, immediates, mask bits and reductions
+    fn element_range(&self, t: InstructionArgsType, index: usize) -> Option<Range<usize>> {
+        match t {
+            InstructionArgsType::Vector
+            | InstructionArgsType::Vector2
+            | InstructionArgsType::VectorAlway16
+            | InstructionArgsType::VectorNarrow2
+            | InstructionArgsType::VectorNarrow4
+            | InstructionArgsType::VectorNarrow8 => Some(self.get_args_range(t, index)),
+            _ => None,
+        }
+    }
+
+    // element `index` of `from` becomes element 0
+    fn copy_element(&mut self, from: &RVVTestData, index: usize) {
+        for operand in [Operand::Lhs, Operand::Rhs, Operand::ResultBefore] {
+            let t = self.operand_type(operand);
+            if t == InstructionArgsType::VectorBit {
+                let bit = get_bit_in_slice(from.operand(operand), index);
+                set_bit_in_slice(self.operand_mut(operand), 0, bit);
+            } else if let Some(src) = from.element_range(t, index) {
+                let dst = self.element_range(t, 0).unwrap();
+                self.operand_mut(operand)[dst].copy_from_slice(&from.operand(operand)[src]);
+            }
+        }
+        let bit = get_bit_in_slice(&from.mask, index);
+        set_bit_in_slice(&mut self.mask, 0, bit);
+        self.res_rvv = self.res_before.clone();
+        self.res_exp = self.res_before.clone();
+    }
+
+    fn zero_elements_except(&mut self, index: usize) {
+        for operand in [Operand::Lhs, Operand::Rhs, Operand::ResultBefore] {
+            let t = self.operand_type(operand);
+            for i in (0..self.avl as usize).filter(|i| *i != index) {
+                if t == InstructionArgsType::VectorBit {
+                    set_bit_in_slice(self.operand_mut(operand), i, 0);
+                } else if let Some(r) = self.element_range(t, i) {
+                    self.operand_mut(operand)[r].fill(0);
+                }
+            }
+        }
+        self.res_rvv = self.res_before.clone();
+        self.res_exp = self.res_before.clone();
+    }
+
+    // false if the operand has no element `index` to simplify
+    fn set_simple_value(&mut self, operand: Operand, index: usize, value: SimpleValue) -> bool {
+        let t = self.operand_type(operand);
+        let range = match t {
+            InstructionArgsType::Scalar => Some(0..8),
+            _ => self.element_range(t, index),
+        };
+        let range = match range {
+            Some(r) => r,
+            None => return false,
+        };
+        let d = &mut self.operand_mut(operand)[range];
+        match value {
+            SimpleValue::Zero => d.fill(0),
+            SimpleValue::One => {
+                d.fill(0);
+                d[0] = 1;
+            }
+            SimpleValue::Max => d.fill(0xFF),
+        }
+        self.res_rvv = self.res_before.clone();
+        self.res_exp = self.res_before.clone();
+        true
+    }
+
+    fn operand_type(&self, operand: Operand) -> InstructionArgsType {
+        match operand {
+            Operand::Lhs => self.lhs_type,
+            Operand::Rhs => self.rhs_type,
+            Operand::ResultBefore => self.res_type,
+        }
+    }
+
+    fn operand(&self, operand: Operand) -> &[u8] {
+        match operand {
+            Operand::Lhs => &self.lhs,
+            Operand::Rhs => &self.rhs,
+            Operand::ResultBefore => &self.res_before,
+        }
+    }
+
+    fn operand_mut(&mut self, operand: Operand) -> &mut [u8] {
+        match operand {
+            Operand::Lhs => &mut self.lhs,
+            Operand::Rhs => &mut self.rhs,
+            Operand::ResultBefore => &mut self.res_before,
+        }
+    }
+
     fn get_args_range(&self, t: InstructionArgsType, index: usize) -> Range<usize> {
         match t {
             InstructionArgsType::None => {
@@ -555,7 +713,148 @@ impl RVVTestData {
     }
 }
 
-fn run_rvv_op(rvv_data: &mut RVVTestData, op: fn(&[u8], &[u8], MaskType)) {
+// With fractional LMUL the destination lives in the low part of v24, the rest
+// of the register is tail and must be left undisturbed.
+fn check_fractional_tail(
+    rvv_data: &RVVTestData,
+    vl: usize,
+    tail_begin: usize,
+    before: &[u8],
+    after: &[u8],
+    desc: &str,
+) {
+    if before[tail_begin..] != after[tail_begin..] {
+        let index = (tail_begin..before.len())
+            .find(|i| before[*i] != after[*i])
+            .unwrap();
+        log!(
+            "[sew = {}, describe = {}] tail of v24 changed at byte {}, tail begins at byte {}",
+            rvv_data.sew,
+            desc,
+            index,
+            tail_begin
+        );
+        log!(
+            "-lmul = {}, avl = {}, vl = {}, strip = {}, mask = {}",
+            rvv_data.lmul,
+            rvv_data.avl,
+            vl,
+            rvv_data.count,
+            rvv_data.mask_type
+        );
+        log!("-before: {:0>2X?}", before);
+        log!("-after: {:0>2X?}", after);
+        panic!("Abort");
+    }
+}
+
+const POISON_SEED_INIT: u64 = 0x9E37_79B9_7F4A_7C15;
+static mut POISON_SEED: u64 = POISON_SEED_INIT;
+
+pub fn reset_poison_seed() {
+    unsafe {
+        POISON_SEED = POISON_SEED_INIT;
+    }
+}
+
+// A new xorshift pattern in every vector register for each op, so a write
+// outside of the destination can't go unnoticed by matching the old value
+fn poison_registers() {
+    let mut x = unsafe {
+        POISON_SEED = POISON_SEED.wrapping_add(1);
+        POISON_SEED
+    };
+    let mut buf = Vec::<u8>::new();
+    buf.resize(4 * VLEN, 0);
+    for chunk in buf.chunks_mut(8) {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        chunk.copy_from_slice(&x.to_le_bytes());
+    }
+    vl8r_all(&buf);
+}
+
+// vxrm and vlenb, vl/vtype/vxsat/vstart may be changed by an instruction
+fn read_preserved_csrs() -> (u64, u64) {
+    let vxrm: u64;
+    let vlenb: u64;
+    unsafe {
+        asm!("csrr {}, 0x00A", "csrr {}, 0xC22", out (reg) vxrm, out (reg) vlenb);
+    }
+    (vxrm, vlenb)
+}
+
+// vl, vtype and vxsat as the instruction left them, for --digest
+fn digest_written_csrs() {
+    let vl: u64;
+    let vtype: u64;
+    let vxsat: u64;
+    unsafe {
+        asm!(
+            "csrr {}, 0xC20",
+            "csrr {}, 0xC21",
+            "csrr {}, 0x009",
+            out (reg) vl,
+            out (reg) vtype,
+            out (reg) vxsat
+        );
+    }
+    for csr in [vl, vtype, vxsat] {
+        update_digest(&csr.to_le_bytes());
+    }
+}
+
+// Number of registers from v24 on the result is written to
+fn destination_registers(rvv_data: &RVVTestData) -> usize {
+    match rvv_data.res_type {
+        InstructionArgsType::VectorBit
+        | InstructionArgsType::VectorRed
+        | InstructionArgsType::VectorRed2 => 1,
+        _ => {
+            let sew = rvv_data.sew;
+            let emul = RVVTestData::get_lmul(rvv_data.lmul) * rvv_data.get_result_sew(sew) as f64
+                / sew as f64;
+            if emul < 1.0 {
+                1
+            } else {
+                emul as usize
+            }
+        }
+    }
+}
+
+// Only the destination group may differ between the snapshots of v0..v31
+fn check_side_effects(rvv_data: &RVVTestData, vl: usize, before: &[u8], after: &[u8], desc: &str) {
+    let vlenb = VLEN / 8;
+    let destination = 24..24 + destination_registers(rvv_data);
+    let changed = (0..32)
+        .filter(|i| !destination.contains(i))
+        .find(|i| before[i * vlenb..(i + 1) * vlenb] != after[i * vlenb..(i + 1) * vlenb]);
+    if let Some(reg) = changed {
+        log!(
+            "[sew = {}, describe = {}] v{} changed, only v{}..v{} are written",
+            rvv_data.sew,
+            desc,
+            reg,
+            destination.start,
+            destination.end - 1
+        );
+        log!(
+            "-lmul = {}, avl = {}, vl = {}, strip = {}, mask = {}",
+            rvv_data.lmul,
+            rvv_data.avl,
+            vl,
+            rvv_data.count,
+            rvv_data.mask_type
+        );
+        log!("-before: {:0>2X?}", &before[reg * vlenb..(reg + 1) * vlenb]);
+        log!("-after: {:0>2X?}", &after[reg * vlenb..(reg + 1) * vlenb]);
+        panic!("Abort");
+    }
+}
+
+fn run_rvv_op(rvv_data: &mut RVVTestData, op: fn(&[u8], &[u8], MaskType), desc: &str) {
     let empty_buf = [0u8; 1];
 
     let mut avl = rvv_data.avl as i64;
@@ -570,13 +869,17 @@ fn run_rvv_op(rvv_data: &mut RVVTestData, op: fn(&[u8], &[u8], MaskType)) {
         }
         avl -= vl as i64;
 
+        // before the operands, the elements past vl keep the poison
+        poison_registers();
+        if rvv_data.zero_vs2_tail {
+            clean_cache_v8();
+        }
         let l = if rvv_data.lhs_type == InstructionArgsType::Immediate
             || rvv_data.lhs_type == InstructionArgsType::UImmediate
             || rvv_data.lhs_type == InstructionArgsType::Scalar
         {
             rvv_data.lhs.as_slice()
         } else {
-            clean_cache_v8();
             vle_v8(rvv_data.get_left_sew(sew), &rvv_data.get_rvv_left());
             &empty_buf
         };
@@ -587,7 +890,6 @@ fn run_rvv_op(rvv_data: &mut RVVTestData, op: fn(&[u8], &[u8], MaskType)) {
         {
             rvv_data.rhs.as_slice()
         } else {
-            clean_cache_v16();
             vle_v16(rvv_data.get_right_sew(sew), &rvv_data.get_rvv_right());
             &empty_buf
         };
@@ -616,30 +918,56 @@ fn run_rvv_op(rvv_data: &mut RVVTestData, op: fn(&[u8], &[u8], MaskType)) {
             }
         };
         vle_v24(rvv_data.get_result_sew(sew), &result);
+        let mut before = Vec::<u8>::new();
+        before.resize(4 * VLEN, 0);
+        vs8r_all(&mut before);
+        let csrs = read_preserved_csrs();
         op.clone()(l, r, mask_type);
+        let mut after = Vec::<u8>::new();
+        after.resize(4 * VLEN, 0);
+        vs8r_all(&mut after);
+        if is_digest() {
+            digest_written_csrs();
+        }
+        check_side_effects(rvv_data, vl, &before, &after, desc);
+        if read_preserved_csrs() != csrs {
+            log!(
+                "[sew = {}, describe = {}] vxrm or vlenb changed, before: {:?}, after: {:?}",
+                rvv_data.sew,
+                desc,
+                csrs,
+                read_preserved_csrs()
+            );
+            panic!("Abort");
+        }
+        if rvv_data.lmul < 0 && rvv_data.res_type != InstructionArgsType::VectorBit {
+            let v24 = 24 * VLEN / 8..25 * VLEN / 8;
+            check_fractional_tail(
+                rvv_data,
+                vl,
+                result_len,
+                &before[v24.clone()],
+                &after[v24],
+                desc,
+            );
+        }
         vse_v24(rvv_data.get_result_sew(sew), &mut result);
         rvv_data.set_rvv_result(&result[..result_len]);
         rvv_data.count += 1;
     }
 }
 
-fn run_op(
+// Runs the reference and the instruction, returns the index of the first
+// element which doesn't match
+fn check_op(
     rvv_data: &mut RVVTestData,
     rvv_op: fn(&[u8], &[u8], MaskType),
     exp_op: VectorCallbackType,
     masked_op: fn(&mut RVVTestData),
     desc: &str,
-) {
-    if is_verbose() {
-        log!(
-            "run with sew = {}, lmul = {}, avl = {}, desc = {}",
-            rvv_data.sew,
-            rvv_data.lmul,
-            rvv_data.avl,
-            desc
-        );
-    }
-
+) -> Option<usize> {
+    rvv_data.res_exp = rvv_data.res_before.clone();
+    rvv_data.res_rvv = rvv_data.res_before.clone();
     for i in 0..rvv_data.avl as usize {
         rvv_data.index = i;
         rvv_data.count = i / rvv_data.theoretically_vl as usize;
@@ -787,53 +1115,274 @@ fn run_op(
         }
     }
 
-    run_rvv_op(rvv_data, rvv_op);
+    run_rvv_op(rvv_data, rvv_op, desc);
 
-    if rvv_data.res_exp != rvv_data.res_rvv {
-        for i in 0..rvv_data.avl as usize {
-            rvv_data.index = i;
-            rvv_data.count = i / rvv_data.theoretically_vl as usize;
-            let exp = rvv_data.get_result_exp();
-            let res = rvv_data.get_result_rvv();
-            let exp_befor = rvv_data.get_result_befor();
+    if rvv_data.res_exp == rvv_data.res_rvv {
+        return None;
+    }
+    for i in 0..rvv_data.avl as usize {
+        rvv_data.index = i;
+        rvv_data.count = i / rvv_data.theoretically_vl as usize;
+        if rvv_data.get_result_exp() != rvv_data.get_result_rvv() {
+            return Some(i);
+        }
+    }
+    None
+}
 
-            if exp != res {
-                log!(
-                "[sew = {}, describe = {}] unexpected values found at index {} \nresult = {:0>2X?} \nexpected = {:0>2X?}",
-                rvv_data.sew, desc, i, res, exp
-            );
-                log!(
-                "more information, \nlhs = {:0>2X?} \nrhs = {:0>2X?} \nexpected_before = {:0>2X?}",
-                rvv_data.get_left(),
-                rvv_data.get_right(),
-                exp_befor
-            );
+fn run_op(
+    rvv_data: &mut RVVTestData,
+    rvv_op: fn(&[u8], &[u8], MaskType),
+    exp_op: VectorCallbackType,
+    masked_op: fn(&mut RVVTestData),
+    desc: &str,
+) {
+    if is_verbose() {
+        log!(
+            "run with sew = {}, lmul = {}, avl = {}, desc = {}",
+            rvv_data.sew,
+            rvv_data.lmul,
+            rvv_data.avl,
+            desc
+        );
+    }
 
-                log!(
-                    "-lmul = {}, avl = {}, vl = {}, mask = {}",
-                    rvv_data.lmul,
-                    rvv_data.avl,
-                    rvv_data.theoretically_vl,
-                    rvv_data.mask_type
-                );
+    begin_config_digest();
+    let failed = check_op(rvv_data, rvv_op, exp_op, masked_op, desc);
+    if is_digest() {
+        log!(
+            "digest {} sew = {}, lmul = {}, avl = {}, mask = {}: {}",
+            desc,
+            rvv_data.sew,
+            rvv_data.lmul,
+            rvv_data.avl,
+            rvv_data.mask_type,
+            finish_config_digest()
+        );
+    }
+    record_instruction(
+        desc,
+        rvv_data.sew,
+        rvv_data.lmul,
+        rvv_data.mask_type != MaskType::Disable,
+    );
 
-                log!("-expected: {:0>2X?}", rvv_data.res_exp);
-                log!("-result: {:0>2X?}", rvv_data.res_rvv);
-                log!("-res_before: {:0>2X?}", rvv_data.res_before);
-                if rvv_data.mask_type != MaskType::Disable {
-                    log!("-mask = {:0>2X?}", &rvv_data.mask);
-                }
-                log!("-lhs: {:0>2X?}", rvv_data.lhs);
-                log!("-rhs: {:0>2X?}", rvv_data.rhs);
-                panic!("Abort");
+    if let Some(i) = failed {
+        rvv_data.index = i;
+        rvv_data.count = i / rvv_data.theoretically_vl as usize;
+        let exp = rvv_data.get_result_exp();
+        let res = rvv_data.get_result_rvv();
+        let exp_befor = rvv_data.get_result_befor();
+
+        log!(
+            "[sew = {}, describe = {}] unexpected values found at index {} \nresult = {:0>2X?} \nexpected = {:0>2X?}",
+            rvv_data.sew, desc, i, res, exp
+        );
+        log!(
+            "more information, \nlhs = {:0>2X?} \nrhs = {:0>2X?} \nexpected_before = {:0>2X?}",
+            rvv_data.get_left(),
+            rvv_data.get_right(),
+            exp_befor
+        );
+
+        log!(
+            "-lmul = {}, avl = {}, vl = {}, mask = {}",
+            rvv_data.lmul,
+            rvv_data.avl,
+            rvv_data.theoretically_vl,
+            rvv_data.mask_type
+        );
+        log!(
+            "-avl strategy = {}, avls = {:?}",
+            get_avl_strategy(),
+            rvv_data.avl_list
+        );
+
+        // the whole vectors are long with a large SEW or LMUL, the shrunk
+        // case below is usually enough
+        if is_verbose() {
+            log!("-expected: {:0>2X?}", rvv_data.res_exp);
+            log!("-result: {:0>2X?}", rvv_data.res_rvv);
+            log!("-res_before: {:0>2X?}", rvv_data.res_before);
+            if rvv_data.mask_type != MaskType::Disable {
+                log!("-mask = {:0>2X?}", &rvv_data.mask);
             }
+            log!("-lhs: {:0>2X?}", rvv_data.lhs);
+            log!("-rhs: {:0>2X?}", rvv_data.rhs);
         }
+        shrink(rvv_data, i, rvv_op, exp_op, masked_op, desc);
+        panic!("Abort");
     }
     if is_verbose() {
         log!("finished");
     }
 }
 
+// Retries a failing op with smaller inputs: a shorter avl, the other elements
+// zeroed, the other mask bits unset and the failing element's operands set to
+// 0, 1 or the all-ones value. Every change after which the op still fails is
+// kept, the smallest failing case is reported.
+fn shrink(
+    rvv_data: &RVVTestData,
+    index: usize,
+    rvv_op: fn(&[u8], &[u8], MaskType),
+    exp_op: VectorCallbackType,
+    masked_op: fn(&mut RVVTestData),
+    desc: &str,
+) {
+    let mut best = rvv_data.truncated(rvv_data.avl);
+    best.res_exp = rvv_data.res_exp.clone();
+    best.res_rvv = rvv_data.res_rvv.clone();
+    let mut index = index;
+    let still_fails =
+        |candidate: &mut RVVTestData| check_op(candidate, rvv_op, exp_op, masked_op, desc);
+
+    // drop the elements after the failing one, then keep only the failing one
+    // at index 0
+    let mut candidate = best.truncated(index as u64 + 1);
+    if let Some(i) = still_fails(&mut candidate) {
+        best = candidate;
+        index = i;
+    }
+    if index != 0 {
+        let mut candidate = best.truncated(1);
+        candidate.copy_element(&best, index);
+        if let Some(i) = still_fails(&mut candidate) {
+            best = candidate;
+            index = i;
+        }
+    }
+
+    let mut candidate = best.truncated(best.avl);
+    candidate.zero_elements_except(index);
+    if let Some(i) = still_fails(&mut candidate) {
+        best = candidate;
+        index = i;
+    }
+
+    if best.mask_type != MaskType::Disable {
+        let mut candidate = best.truncated(best.avl);
+        let bit = get_bit_in_slice(&candidate.mask, index);
+        candidate.mask.fill(0);
+        set_bit_in_slice(&mut candidate.mask, index, bit);
+        if let Some(i) = still_fails(&mut candidate) {
+            best = candidate;
+            index = i;
+        }
+    }
+
+    for operand in [Operand::Lhs, Operand::Rhs, Operand::ResultBefore] {
+        for value in [SimpleValue::Zero, SimpleValue::One, SimpleValue::Max] {
+            let mut candidate = best.truncated(best.avl);
+            if !candidate.set_simple_value(operand, index, value) {
+                break;
+            }
+            if let Some(i) = still_fails(&mut candidate) {
+                best = candidate;
+                index = i;
+                break;
+            }
+        }
+    }
+
+    best.index = index;
+    best.count = index / best.theoretically_vl;
+    log!(
+        "[sew = {}, describe = {}] shrunk to avl = {}, vl = {}, index = {}",
+        best.sew,
+        desc,
+        best.avl,
+        best.theoretically_vl,
+        index
+    );
+    log!(
+        "-lhs = {:0>2X?}, rhs = {:0>2X?}",
+        best.get_left(),
+        best.get_right()
+    );
+    log!(
+        "-result = {:0>2X?}, expected = {:0>2X?}, expected_before = {:0>2X?}",
+        best.get_result_rvv(),
+        best.get_result_exp(),
+        best.get_result_befor()
+    );
+    if best.mask_type != MaskType::Disable {
+        log!("-mask bit = {}", get_bit_in_slice(&best.mask, index));
+    }
+}
+
+// With vl = 0 no element operations are performed, the destination register
+// group (including element 0 of reductions and the tail) must not be updated.
+fn run_zero_vl(
+    left_type: InstructionArgsType,
+    right_type: InstructionArgsType,
+    mask_type: MaskType,
+    rvv_op: fn(&[u8], &[u8], MaskType),
+    sew: u64,
+    lmul: i64,
+    imm: i64,
+    desc: &str,
+) {
+    let mut rng = BestNumberRng::default();
+
+    let mut buf = [0u8; VLEN];
+    rng.fill(&mut buf[..]);
+    vl8r_v8(&buf);
+    rng.fill(&mut buf[..]);
+    vl8r_v16(&buf);
+    let mut mask = [0u8; VLEN / 8];
+    rng.fill_mask(&mut mask[..]);
+    vl1r_v0(&mask);
+
+    let mut before = [0u8; VLEN];
+    rng.fill(&mut before[..]);
+    vl8r_v24(&before);
+
+    let empty_buf = [0u8; 1];
+    let imm = imm.to_le_bytes();
+    let scalar = rng.gen::<u64>().to_le_bytes();
+    let l: &[u8] = if left_type.is_imm() {
+        &imm
+    } else if left_type == InstructionArgsType::Scalar {
+        &scalar
+    } else {
+        &empty_buf
+    };
+    let r: &[u8] = if right_type.is_imm() {
+        &imm
+    } else if right_type == InstructionArgsType::Scalar {
+        &scalar
+    } else {
+        &empty_buf
+    };
+
+    let vl = vsetvl(0, sew, lmul);
+    assert_eq!(vl, 0);
+    rvv_op(l, r, mask_type);
+
+    let mut after = [0u8; VLEN];
+    vs8r_v24(&mut after);
+    if before != after {
+        let index = before.iter().zip(after.iter()).position(|(b, a)| b != a);
+        log!(
+            "[sew = {}, describe = {}] destination updated with vl = 0 at byte {:?}",
+            sew,
+            desc,
+            index
+        );
+        log!(
+            "-lmul = {}, mask = {}, lhs = {:0>2X?}, rhs = {:0>2X?}",
+            lmul,
+            mask_type,
+            l,
+            r
+        );
+        log!("-before: {:0>2X?}", before);
+        log!("-after: {:0>2X?}", after);
+        panic!("Abort");
+    }
+}
+
 fn run_template_ext(
     vd_type: InstructionArgsType,
     left_type: InstructionArgsType,
@@ -872,7 +1421,8 @@ fn run_template_ext(
 
     for sew in sews {
         for lmul in lmuls.clone() {
-            for avl in avl_iterator(sew.clone(), lmul, 2) {
+            let avl_list = avl_iterator(sew, lmul, get_avl_strategy());
+            for avl in avl_list.clone() {
                 if vsetvl(avl, sew, lmul) == 0 {
                     continue;
                 }
@@ -901,6 +1451,10 @@ fn run_template_ext(
                     lmul.clone(),
                     avl,
                 );
+                rvv_data.avl_list = avl_list.clone();
+                // the whole-data refs (vrgather) read vs2 by index up to VLMAX
+                // and take the elements past vl as 0
+                rvv_data.zero_vs2_tail = matches!(exp_op, VectorCallbackType::None(_));
                 rvv_data.rng_fill();
                 if left_type.is_imm() {
                     rvv_data.lhs.copy_from_slice(&imm.to_le_bytes());
@@ -918,6 +1472,20 @@ fn run_template_ext(
                     }
                 }
             }
+
+            if vsetvl(1, sew, lmul) == 0
+                || !before_op.clone()(sew as f64, RVVTestData::get_lmul(lmul), 0)
+            {
+                continue;
+            }
+            let zero_vl_masks = if mask_type == MaskType::Enable {
+                [MaskType::Disable, MaskType::Enable].to_vec()
+            } else {
+                [mask_type].to_vec()
+            };
+            for m in zero_vl_masks {
+                run_zero_vl(left_type, right_type, m, rvv_op, sew, lmul, imm, desc);
+            }
         }
     }
 }
@@ -1217,6 +1785,29 @@ pub fn run_template_w_vx(
     );
 }
 
+pub fn run_template_w_vi(
+    expected_op: fn(&[u8], i64, &mut [u8]),
+    rvv_op: fn(&[u8], &[u8], MaskType),
+    enable_mask: bool,
+    desc: &str,
+) {
+    run_template_ext(
+        InstructionArgsType::Vector2,
+        InstructionArgsType::Vector,
+        InstructionArgsType::UImmediate,
+        if enable_mask {
+            MaskType::Enable
+        } else {
+            MaskType::Disable
+        },
+        rvv_op,
+        VectorCallbackType::VI(expected_op),
+        befor_op_wide,
+        masked_op_default,
+        desc,
+    );
+}
+
 pub fn run_template_v_wv(
     expected_op: fn(&[u8], &[u8], &mut [u8]),
     rvv_op: fn(&[u8], &[u8], MaskType),
@@ -1281,6 +1872,79 @@ pub fn run_template_v_wi(
     );
 }
 
+// t0 holds rs1 of the `.vx` ops in `rvv_asm_x!` and `raw_op_x!`, it must
+// still hold it after the instruction
+pub fn check_scalar_operand(x: u64, after: u64) {
+    if x != after {
+        log!(
+            "scalar operand changed by the instruction, before: {:#x}, after: {:#x}",
+            x,
+            after
+        );
+        panic!("Abort");
+    }
+}
+
+// A reference op written once for every SEW, `expected_vv::<O>` and friends
+// instantiate it with the element type of the SEW under test.
+pub trait BinaryOp {
+    fn op<T: RvvElement>(lhs: T, rhs: T) -> T;
+}
+
+macro_rules! for_each_sew {
+    ($len:expr, $run:ident, $($arg:expr),*) => {
+        match $len {
+            1 => $run::<O, u8>($($arg),*),
+            2 => $run::<O, u16>($($arg),*),
+            4 => $run::<O, u32>($($arg),*),
+            8 => $run::<O, u64>($($arg),*),
+            16 => $run::<O, u128>($($arg),*),
+            32 => $run::<O, E256>($($arg),*),
+            64 => $run::<O, E512>($($arg),*),
+            128 => $run::<O, E1024>($($arg),*),
+            _ => panic!("Invalid sew"),
+        }
+    };
+}
+
+pub fn expected_vv<O: BinaryOp>(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+    fn run<O: BinaryOp, T: RvvElement>(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+        let res = O::op(T::from_slice(lhs), T::from_slice(rhs));
+        T::to_slice(res, result);
+    }
+    assert!(lhs.len() == rhs.len() && rhs.len() == result.len());
+    for_each_sew!(lhs.len(), run, lhs, rhs, result)
+}
+
+// x is sign-extended to SEW
+pub fn expected_vx<O: BinaryOp>(lhs: &[u8], x: u64, result: &mut [u8]) {
+    fn run<O: BinaryOp, T: RvvElement>(lhs: &[u8], x: u64, result: &mut [u8]) {
+        let res = O::op(T::from_slice(lhs), T::from_i64(x as i64));
+        T::to_slice(res, result);
+    }
+    assert!(lhs.len() == result.len());
+    for_each_sew!(lhs.len(), run, lhs, x, result)
+}
+
+// x is zero-extended to SEW, for the unsigned ops
+pub fn expected_vxu<O: BinaryOp>(lhs: &[u8], x: u64, result: &mut [u8]) {
+    fn run<O: BinaryOp, T: RvvElement>(lhs: &[u8], x: u64, result: &mut [u8]) {
+        let res = O::op(T::from_slice(lhs), T::from_u64(x));
+        T::to_slice(res, result);
+    }
+    assert!(lhs.len() == result.len());
+    for_each_sew!(lhs.len(), run, lhs, x, result)
+}
+
+pub fn expected_vi<O: BinaryOp>(lhs: &[u8], imm: i64, result: &mut [u8]) {
+    fn run<O: BinaryOp, T: RvvElement>(lhs: &[u8], imm: i64, result: &mut [u8]) {
+        let res = O::op(T::from_slice(lhs), T::from_i64(imm));
+        T::to_slice(res, result);
+    }
+    assert!(lhs.len() == result.len());
+    for_each_sew!(lhs.len(), run, lhs, imm, result)
+}
+
 pub fn run_template_v_vv(
     expected_op: fn(&[u8], &[u8], &mut [u8]),
     rvv_op: fn(&[u8], &[u8], MaskType),
@@ -0,0 +1,374 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use ckb_std::syscalls::debug;
+use core::arch::asm;
+use rand::RngCore;
+use rvv_testcases::coverage::record_instruction;
+use rvv_testcases::intrinsic::{vl8r_all, vs8r_all, vsetvl};
+use rvv_testcases::isa::{encode, lookup, OperandForm};
+use rvv_testcases::log;
+use rvv_testcases::misc::{is_full, is_verbose, RvvElement, VLEN};
+use rvv_testcases::rng::BestNumberRng;
+use rvv_testcases::runner::BinaryOp;
+use rvv_testcases::vmodel::{Operand, VState};
+
+use crate::vop_vv_cases::{Add, And, Max, Maxu, Min, Minu, Mul, Or, Xor};
+use crate::vop_vx_cases::{Rsub, Sub};
+
+// Random straight-line instruction sequences, compared against `VState` after
+// every step. Bugs which only show up in sequences, e.g. a stale vtype after
+// vsetvl or register groups after an LMUL change, are missed by the runner
+// which executes one instruction per configuration.
+
+const VLENB: usize = VLEN / 8;
+
+pub struct Saddu;
+impl BinaryOp for Saddu {
+    fn op<T: RvvElement>(lhs: T, rhs: T) -> T {
+        T::saturating_add_u(lhs, rhs)
+    }
+}
+
+pub struct Sadd;
+impl BinaryOp for Sadd {
+    fn op<T: RvvElement>(lhs: T, rhs: T) -> T {
+        T::saturating_add_s(lhs, rhs)
+    }
+}
+
+pub struct Ssubu;
+impl BinaryOp for Ssubu {
+    fn op<T: RvvElement>(lhs: T, rhs: T) -> T {
+        T::saturating_sub_u(lhs, rhs)
+    }
+}
+
+pub struct Ssub;
+impl BinaryOp for Ssub {
+    fn op<T: RvvElement>(lhs: T, rhs: T) -> T {
+        T::saturating_sub_s(lhs, rhs)
+    }
+}
+
+// Emits `insn vd, vs2, rhs` with the registers in the encoding, so there is
+// one arm per combination of v0, v8, v16 and v24. These groups are aligned
+// for every LMUL.
+macro_rules! seq_word {
+    (@vs2 $insn:ident, $vd:literal, $vs2:expr, $rhs:tt) => {
+        match $vs2 {
+            0 => seq_word!(@rhs $insn, $vd, 0, $rhs),
+            8 => seq_word!(@rhs $insn, $vd, 8, $rhs),
+            16 => seq_word!(@rhs $insn, $vd, 16, $rhs),
+            24 => seq_word!(@rhs $insn, $vd, 24, $rhs),
+            _ => panic!("Abort"),
+        }
+    };
+    (@rhs $insn:ident, $vd:literal, $vs2:literal, (vv $vs1:expr)) => {
+        match $vs1 {
+            0 => asm!(".word {}", const encode($insn.0, $insn.1, false, $vd, $vs2, 0)),
+            8 => asm!(".word {}", const encode($insn.0, $insn.1, false, $vd, $vs2, 8)),
+            16 => asm!(".word {}", const encode($insn.0, $insn.1, false, $vd, $vs2, 16)),
+            24 => asm!(".word {}", const encode($insn.0, $insn.1, false, $vd, $vs2, 24)),
+            _ => panic!("Abort"),
+        }
+    };
+    (@rhs $insn:ident, $vd:literal, $vs2:literal, (vx $x:expr)) => {
+        asm!(
+            "mv t0, {}",
+            ".word {}",
+            in (reg) $x,
+            const encode($insn.0, $insn.1, false, $vd, $vs2, 5)
+        )
+    };
+    (@rhs $insn:ident, $vd:literal, $vs2:literal, (vi $imm:expr)) => {
+        seq_word!(@vi $insn, $vd, $vs2, $imm, [-16, -1, 0, 1, 7, 15])
+    };
+    (@vi $insn:ident, $vd:literal, $vs2:literal, $imm:expr, [$($i:literal),*]) => {
+        match $imm {
+            $($i => asm!(
+                ".word {}",
+                const encode($insn.0, $insn.1, false, $vd, $vs2, $i as i32 as u32)
+            ),)*
+            _ => panic!("Abort"),
+        }
+    };
+    ($insn:ident, $vd:expr, $vs2:expr, $rhs:tt) => {
+        match $vd {
+            0 => seq_word!(@vs2 $insn, 0, $vs2, $rhs),
+            8 => seq_word!(@vs2 $insn, 8, $vs2, $rhs),
+            16 => seq_word!(@vs2 $insn, 16, $vs2, $rhs),
+            24 => seq_word!(@vs2 $insn, 24, $vs2, $rhs),
+            _ => panic!("Abort"),
+        }
+    };
+}
+
+const GROUPS: [usize; 4] = [0, 8, 16, 24];
+// must match the list in `seq_word!`
+const IMMEDIATES: [i64; 6] = [-16, -1, 0, 1, 7, 15];
+
+#[derive(Clone, Copy)]
+enum Form {
+    VV,
+    VX,
+    VI,
+}
+
+struct Op {
+    name: &'static str,
+    form: Form,
+    // runs the instruction, then the model
+    run: fn(&mut VState, usize, usize, Operand),
+}
+
+// `name, form, ref[, wrapping ref];`, the wrapping ref is given for the
+// saturating instructions, see `VState::execute_saturating`.
+macro_rules! seq_ops {
+    (@form vv) => {
+        Form::VV
+    };
+    (@form vx) => {
+        Form::VX
+    };
+    (@form vi) => {
+        Form::VI
+    };
+    (@wrap $ref:ty) => {
+        $ref
+    };
+    (@wrap $ref:ty, $wrap:ty) => {
+        $wrap
+    };
+    (@run vv, $insn:ident, $vd:ident, $vs2:ident, $rhs:ident) => {
+        match $rhs {
+            Operand::Vector(vs1) => unsafe { seq_word!($insn, $vd, $vs2, (vv vs1)) },
+            _ => panic!("Abort"),
+        }
+    };
+    (@run vx, $insn:ident, $vd:ident, $vs2:ident, $rhs:ident) => {
+        match $rhs {
+            Operand::Scalar(x) => unsafe { seq_word!($insn, $vd, $vs2, (vx x)) },
+            _ => panic!("Abort"),
+        }
+    };
+    (@run vi, $insn:ident, $vd:ident, $vs2:ident, $rhs:ident) => {
+        match $rhs {
+            Operand::Immediate(imm) => unsafe { seq_word!($insn, $vd, $vs2, (vi imm)) },
+            _ => panic!("Abort"),
+        }
+    };
+    ($($name:ident, $form:ident, $ref:ty $(, $wrap:ty)?;)*) => {
+        const OPS: &[Op] = &[$(
+            Op {
+                name: concat!(stringify!($name), ".", stringify!($form)),
+                form: seq_ops!(@form $form),
+                run: {
+                    fn run(state: &mut VState, vd: usize, vs2: usize, rhs: Operand) {
+                        const INSN: (u8, OperandForm) =
+                            lookup(concat!(stringify!($name), ".", stringify!($form)));
+                        seq_ops!(@run $form, INSN, vd, vs2, rhs);
+                        state.execute_saturating::<$ref, seq_ops!(@wrap $ref $(, $wrap)?)>(
+                            vd, vs2, rhs,
+                        );
+                    }
+                    run
+                },
+            },
+        )*];
+    };
+}
+
+seq_ops! {
+    vadd, vv, Add;
+    vsub, vv, Sub;
+    vand, vv, And;
+    vor, vv, Or;
+    vxor, vv, Xor;
+    vmul, vv, Mul;
+    vminu, vv, Minu;
+    vmin, vv, Min;
+    vmaxu, vv, Maxu;
+    vmax, vv, Max;
+    vsaddu, vv, Saddu, Add;
+    vsadd, vv, Sadd, Add;
+    vssubu, vv, Ssubu, Sub;
+    vssub, vv, Ssub, Sub;
+    vadd, vx, Add;
+    vsub, vx, Sub;
+    vrsub, vx, Rsub;
+    vand, vx, And;
+    vor, vx, Or;
+    vxor, vx, Xor;
+    vmul, vx, Mul;
+    vmax, vx, Max;
+    vsaddu, vx, Saddu, Add;
+    vsadd, vx, Sadd, Add;
+    vssubu, vx, Ssubu, Sub;
+    vssub, vx, Ssub, Sub;
+    vadd, vi, Add;
+    vrsub, vi, Rsub;
+    vand, vi, And;
+    vor, vi, Or;
+    vxor, vi, Xor;
+    vsaddu, vi, Saddu, Add;
+    vsadd, vi, Sadd, Add;
+}
+
+// vl, vtype, vxrm, vxsat
+fn read_csrs() -> (u64, u64, u64, u64) {
+    let vl: u64;
+    let vtype: u64;
+    let vxrm: u64;
+    let vxsat: u64;
+    unsafe {
+        asm!(
+            "csrr {}, 0xC20",
+            "csrr {}, 0xC21",
+            "csrr {}, 0x00A",
+            "csrr {}, 0x009",
+            out (reg) vl,
+            out (reg) vtype,
+            out (reg) vxrm,
+            out (reg) vxsat
+        );
+    }
+    (vl, vtype, vxrm, vxsat)
+}
+
+fn write_vxrm(vxrm: u64) {
+    unsafe {
+        asm!("csrw 0x00A, {}", in (reg) vxrm);
+    }
+}
+
+fn write_vxsat(vxsat: u64) {
+    unsafe {
+        asm!("csrw 0x009, {}", in (reg) vxsat);
+    }
+}
+
+// SEW <= ELEN * LMUL, vill is never set
+fn random_config(rng: &mut BestNumberRng) -> (u64, i64) {
+    let sews = [8, 16, 32, 64, 128, 256, 512, 1024];
+    let lmuls = [-8, -4, -2, 1, 2, 4, 8];
+    loop {
+        let sew = sews[(rng.next_u64() % sews.len() as u64) as usize];
+        let lmul = lmuls[(rng.next_u64() % lmuls.len() as u64) as usize];
+        if lmul > 0 || sew * (-lmul) as u64 <= 1024 {
+            return (sew, lmul);
+        }
+    }
+}
+
+fn random_group(rng: &mut BestNumberRng) -> usize {
+    GROUPS[(rng.next_u64() % GROUPS.len() as u64) as usize]
+}
+
+fn check(state: &VState, listing: &[String]) {
+    let mut registers = vec![0u8; 32 * VLENB];
+    vs8r_all(&mut registers);
+    let (vl, vtype, vxrm, vxsat) = read_csrs();
+    let csrs = (state.vl, state.vtype(), state.vxrm, state.vxsat);
+    let mismatch = (0..32).find(|i| state.register(*i) != &registers[i * VLENB..(i + 1) * VLENB]);
+    if mismatch.is_none() && csrs == (vl, vtype, vxrm, vxsat) {
+        return;
+    }
+    log!("instruction sequence doesn't match the model after the last step:");
+    for (i, insn) in listing.iter().enumerate() {
+        log!("{:>4}: {}", i, insn);
+    }
+    log!("sew = {}, lmul = {}", state.sew, state.lmul);
+    log!(
+        "-expected: vl = {}, vtype = {:#x}, vxrm = {}, vxsat = {}",
+        csrs.0,
+        csrs.1,
+        csrs.2,
+        csrs.3
+    );
+    log!(
+        "-result: vl = {}, vtype = {:#x}, vxrm = {}, vxsat = {}",
+        vl,
+        vtype,
+        vxrm,
+        vxsat
+    );
+    if let Some(i) = mismatch {
+        log!("v{} doesn't match", i);
+        log!("-expected: {:0>2X?}", state.register(i));
+        log!("-result: {:0>2X?}", &registers[i * VLENB..(i + 1) * VLENB]);
+    }
+    panic!("Abort");
+}
+
+fn run_sequence(rng: &mut BestNumberRng, length: usize) {
+    let mut registers = vec![0u8; 32 * VLENB];
+    rng.fill_bytes(&mut registers);
+    vl8r_all(&registers);
+    write_vxrm(0);
+    write_vxsat(0);
+    let mut state = VState::new(&registers);
+    let mut listing: Vec<String> = Vec::new();
+
+    for step in 0..length {
+        let choice = rng.next_u64() % 16;
+        if step == 0 || choice < 2 {
+            let (sew, lmul) = random_config(rng);
+            let avl = rng.next_u64() % (VState::vlmax(sew, lmul) * 2 + 2);
+            listing.push(format!(
+                "vsetvl avl = {}, sew = {}, lmul = {}",
+                avl, sew, lmul
+            ));
+            vsetvl(avl, sew, lmul);
+            state.vsetvl(avl, sew, lmul);
+        } else if choice == 2 {
+            let vxrm = rng.next_u64() % 4;
+            listing.push(format!("csrw vxrm, {}", vxrm));
+            write_vxrm(vxrm);
+            state.vxrm = vxrm;
+        } else if choice == 3 {
+            listing.push(String::from("csrw vxsat, 0"));
+            write_vxsat(0);
+            state.vxsat = 0;
+        } else {
+            let op = &OPS[(rng.next_u64() % OPS.len() as u64) as usize];
+            let vd = random_group(rng);
+            let vs2 = random_group(rng);
+            let (rhs, text) = match op.form {
+                Form::VV => {
+                    let vs1 = random_group(rng);
+                    (Operand::Vector(vs1), format!("v{}", vs1))
+                }
+                Form::VX => {
+                    let x = rng.next_u64();
+                    (Operand::Scalar(x), format!("{:#x}", x))
+                }
+                Form::VI => {
+                    let imm = IMMEDIATES[(rng.next_u64() % IMMEDIATES.len() as u64) as usize];
+                    (Operand::Immediate(imm), format!("{}", imm))
+                }
+            };
+            listing.push(format!("{} v{}, v{}, {}", op.name, vd, vs2, text));
+            (op.run)(&mut state, vd, vs2, rhs);
+            record_instruction(op.name, state.sew, state.lmul, false);
+        }
+        check(&state, &listing);
+    }
+}
+
+// The sequences write random vxrm values, the later cases expect vxrm/vxsat as
+// they were before
+pub fn test_sequence() {
+    let (_, _, vxrm, vxsat) = read_csrs();
+    let (count, length) = if is_full() { (64, 64) } else { (16, 32) };
+    let mut rng = BestNumberRng::default();
+    for i in 0..count {
+        if is_verbose() {
+            log!("sequence {}, {} steps", i, length);
+        }
+        run_sequence(&mut rng, length);
+    }
+    write_vxrm(vxrm);
+    write_vxsat(vxsat);
+}
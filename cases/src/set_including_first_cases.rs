@@ -3,12 +3,13 @@ use rand::Rng;
 use rvv_asm::rvv_asm;
 
 use ckb_std::syscalls::debug;
+use rvv_testcases::coverage::record_instruction;
 use rvv_testcases::intrinsic::{vl1r_v0, vl1r_v24, vl1r_v8, vs1r_v24, vsetvl};
 use rvv_testcases::log;
 use rvv_testcases::misc::{get_bit_in_slice, is_verbose, set_bit_in_slice, VLEN};
 use rvv_testcases::rng::BestNumberRng;
 
-fn run(enable_mask: bool) {
+fn run(enable_mask: bool, avl: u64) {
     if is_verbose() {
         log!("test vmsif.m");
     }
@@ -25,8 +26,9 @@ fn run(enable_mask: bool) {
     rng.fill(&mut expected_before[..]);
     expected.copy_from_slice(&expected_before[..]);
 
-    let vl = vsetvl(8, 256, 1) as usize;
-    assert_eq!(vl, 8);
+    let vl = vsetvl(avl, 256, 1) as usize;
+    assert_eq!(vl, avl as usize);
+    record_instruction("vmsif.m", 256, 1, enable_mask);
 
     let mut index = VLEN as usize;
     for i in 0..vl as usize {
@@ -81,8 +83,9 @@ fn run(enable_mask: bool) {
             expected
         );
         log!(
-            "more information, enable_mask = {}, index = {}, vs2 = {:?}, mask = {:?}",
+            "more information, enable_mask = {}, vl = {}, index = {}, vs2 = {:?}, mask = {:?}",
             enable_mask,
+            vl,
             index,
             vs2,
             mask
@@ -95,6 +98,9 @@ fn run(enable_mask: bool) {
 }
 
 pub fn test_set_including_first() {
-    run(false);
-    run(true);
+    run(false, 8);
+    run(true, 8);
+    // vl = 0, the destination must not be updated
+    run(false, 0);
+    run(true, 0);
 }
@@ -1,6 +1,8 @@
 use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E128, E256, E512, E64};
 use rvv_asm::rvv_asm;
+use rvv_testcases::rvv_asm_x;
+use rvv_testcases::rvv_op_i;
 use rvv_testcases::{
     misc::{conver_to_i1024, conver_to_i128, conver_to_i256, conver_to_i512},
     runner::{run_template_v_vi, run_template_v_vv, run_template_v_vx, MaskType},
@@ -89,10 +91,10 @@ fn test_vsaddu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vsaddu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vsaddu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vsaddu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vsaddu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -243,310 +245,7 @@ fn expected_op_saddu_vi(lhs: &[u8], imm: i64, result: &mut [u8]) {
 fn test_vsaddu_vi() {
     fn op(_: &[u8], rhs: &[u8], mask_type: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, -1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                0 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 0, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 0");
-                    }
-                    _ => panic!("Abort"),
-                },
-                1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsaddu.vi v24, v8, 16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vsaddu.vi", imm, mask_type);
     }
 
     run_template_v_vi(expected_op_saddu_vi, op, true, true, "vsaddu.vi");
@@ -637,10 +336,10 @@ fn test_vsadd_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vsadd.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vsadd.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vsadd.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vsadd.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -752,310 +451,7 @@ fn expected_op_sadd_vi(lhs: &[u8], imm: i64, result: &mut [u8]) {
 fn test_vsadd_vi() {
     fn op(_: &[u8], rhs: &[u8], mask_type: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                -16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                -1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, -1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, -1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                0 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 0, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 0");
-                    }
-                    _ => panic!("Abort"),
-                },
-                1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsadd.vi v24, v8, 16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsadd.vi v24, v8, 16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(simm5, "vsadd.vi", imm, mask_type);
     }
 
     run_template_v_vi(expected_op_sadd_vi, op, true, true, "vsadd.vi");
@@ -1144,10 +540,10 @@ fn test_vssubu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vssubu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vssubu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vssubu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vssubu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -1335,10 +731,10 @@ fn test_vssub_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vssub.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vssub.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vssub.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vssub.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -1,6 +1,7 @@
 use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E128, E2048, E256, E512};
 use rvv_asm::rvv_asm;
+use rvv_testcases::rvv_asm_x;
 use rvv_testcases::{
     misc::{conver_to_i1024, conver_to_i2048, conver_to_i256, conver_to_i512},
     runner::{
@@ -194,10 +195,10 @@ fn test_vmacc_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmacc.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmacc.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmacc.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmacc.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -389,10 +390,10 @@ fn test_vnmsac_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vnmsac.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vnmsac.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vnmsac.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vnmsac.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -584,10 +585,10 @@ fn test_vmadd_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmadd.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmadd.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmadd.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmadd.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -779,10 +780,10 @@ fn test_vnmsub_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vnmsub.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vnmsub.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vnmsub.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vnmsub.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -978,10 +979,10 @@ fn test_vwmaccu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmaccu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmaccu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmaccu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmaccu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -1185,10 +1186,10 @@ fn test_vwmacc_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmacc.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmacc.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmacc.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmacc.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -1384,10 +1385,10 @@ fn test_vwmaccsu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmaccsu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmaccsu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmaccsu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmaccsu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -1484,10 +1485,10 @@ fn test_vwmaccus_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmaccus.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmaccus.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmaccus.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmaccus.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
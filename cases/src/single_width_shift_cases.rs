@@ -2,6 +2,8 @@ use core::{arch::asm, convert::TryInto};
 
 use eint::{Eint, E1024, E128, E256, E512};
 use rvv_asm::rvv_asm;
+use rvv_testcases::rvv_asm_x;
+use rvv_testcases::rvv_op_i;
 use rvv_testcases::{
     misc::{to_i16, to_i32, to_i64, to_i8, to_u16, to_u32, to_u64, to_u8},
     runner::{run_template_v_vi, run_template_v_vv, run_template_v_vx, MaskType},
@@ -303,10 +305,10 @@ fn test_vsll_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vsll.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vsll.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vsll.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vsll.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -320,301 +322,7 @@ fn test_vsll_vi() {
     }
     fn op(_: &[u8], rhs: &[u8], mask_type: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                0 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 0, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 0");
-                    }
-                    _ => panic!("Abort"),
-                },
-                1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                17 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 17, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 17");
-                    }
-                    _ => panic!("Abort"),
-                },
-                18 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 18, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 18");
-                    }
-                    _ => panic!("Abort"),
-                },
-                19 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 19, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 19");
-                    }
-                    _ => panic!("Abort"),
-                },
-                20 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 20, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 20");
-                    }
-                    _ => panic!("Abort"),
-                },
-                21 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 21, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 21");
-                    }
-                    _ => panic!("Abort"),
-                },
-                22 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 22, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 22");
-                    }
-                    _ => panic!("Abort"),
-                },
-                23 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 23, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 23");
-                    }
-                    _ => panic!("Abort"),
-                },
-                24 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 24, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 24");
-                    }
-                    _ => panic!("Abort"),
-                },
-                25 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 25, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 25");
-                    }
-                    _ => panic!("Abort"),
-                },
-                26 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 26, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 26");
-                    }
-                    _ => panic!("Abort"),
-                },
-                27 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 27, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 27");
-                    }
-                    _ => panic!("Abort"),
-                },
-                28 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 28, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 28");
-                    }
-                    _ => panic!("Abort"),
-                },
-                29 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 29, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 29");
-                    }
-                    _ => panic!("Abort"),
-                },
-                30 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 30, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 30");
-                    }
-                    _ => panic!("Abort"),
-                },
-                31 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsll.vi v24, v8, 31, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsll.vi v24, v8, 31");
-                    }
-                    _ => panic!("Abort"),
-                },
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(uimm5, "vsll.vi", imm, mask_type);
     }
 
     run_template_v_vi(exp_op, op, true, false, "vsll.vi");
@@ -676,10 +384,10 @@ fn test_vsrl_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vsrl.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vsrl.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vsrl.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vsrl.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -693,301 +401,7 @@ fn test_vsrl_vi() {
     }
     fn op(_: &[u8], rhs: &[u8], mask_type: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                0 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 0, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 0");
-                    }
-                    _ => panic!("Abort"),
-                },
-                1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                17 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 17, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 17");
-                    }
-                    _ => panic!("Abort"),
-                },
-                18 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 18, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 18");
-                    }
-                    _ => panic!("Abort"),
-                },
-                19 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 19, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 19");
-                    }
-                    _ => panic!("Abort"),
-                },
-                20 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 20, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 20");
-                    }
-                    _ => panic!("Abort"),
-                },
-                21 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 21, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 21");
-                    }
-                    _ => panic!("Abort"),
-                },
-                22 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 22, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 22");
-                    }
-                    _ => panic!("Abort"),
-                },
-                23 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 23, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 23");
-                    }
-                    _ => panic!("Abort"),
-                },
-                24 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 24, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 24");
-                    }
-                    _ => panic!("Abort"),
-                },
-                25 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 25, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 25");
-                    }
-                    _ => panic!("Abort"),
-                },
-                26 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 26, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 26");
-                    }
-                    _ => panic!("Abort"),
-                },
-                27 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 27, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 27");
-                    }
-                    _ => panic!("Abort"),
-                },
-                28 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 28, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 28");
-                    }
-                    _ => panic!("Abort"),
-                },
-                29 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 29, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 29");
-                    }
-                    _ => panic!("Abort"),
-                },
-                30 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 30, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 30");
-                    }
-                    _ => panic!("Abort"),
-                },
-                31 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsrl.vi v24, v8, 31, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsrl.vi v24, v8, 31");
-                    }
-                    _ => panic!("Abort"),
-                },
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(uimm5, "vsrl.vi", imm, mask_type);
     }
     run_template_v_vi(exp_op, op, true, false, "vsrl.vi");
 }
@@ -1048,10 +462,10 @@ fn test_vsra_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vsra.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vsra.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vsra.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vsra.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -1065,301 +479,7 @@ fn test_vsra_vi() {
     }
     fn op(_: &[u8], rhs: &[u8], mask_type: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
-        unsafe {
-            match imm {
-                0 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 0, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 0");
-                    }
-                    _ => panic!("Abort"),
-                },
-                1 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 1, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 1");
-                    }
-                    _ => panic!("Abort"),
-                },
-                2 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 2, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 2");
-                    }
-                    _ => panic!("Abort"),
-                },
-                3 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 3, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 3");
-                    }
-                    _ => panic!("Abort"),
-                },
-                4 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 4, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 4");
-                    }
-                    _ => panic!("Abort"),
-                },
-                5 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 5, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 5");
-                    }
-                    _ => panic!("Abort"),
-                },
-                6 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 6, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 6");
-                    }
-                    _ => panic!("Abort"),
-                },
-                7 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 7, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 7");
-                    }
-                    _ => panic!("Abort"),
-                },
-                8 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 8, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 8");
-                    }
-                    _ => panic!("Abort"),
-                },
-                9 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 9, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 9");
-                    }
-                    _ => panic!("Abort"),
-                },
-                10 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 10, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 10");
-                    }
-                    _ => panic!("Abort"),
-                },
-                11 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 11, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 11");
-                    }
-                    _ => panic!("Abort"),
-                },
-                12 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 12, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 12");
-                    }
-                    _ => panic!("Abort"),
-                },
-                13 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 13, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 13");
-                    }
-                    _ => panic!("Abort"),
-                },
-                14 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 14, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 14");
-                    }
-                    _ => panic!("Abort"),
-                },
-                15 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 15, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 15");
-                    }
-                    _ => panic!("Abort"),
-                },
-                16 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 16, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 16");
-                    }
-                    _ => panic!("Abort"),
-                },
-                17 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 17, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 17");
-                    }
-                    _ => panic!("Abort"),
-                },
-                18 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 18, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 18");
-                    }
-                    _ => panic!("Abort"),
-                },
-                19 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 19, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 19");
-                    }
-                    _ => panic!("Abort"),
-                },
-                20 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 20, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 20");
-                    }
-                    _ => panic!("Abort"),
-                },
-                21 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 21, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 21");
-                    }
-                    _ => panic!("Abort"),
-                },
-                22 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 22, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 22");
-                    }
-                    _ => panic!("Abort"),
-                },
-                23 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 23, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 23");
-                    }
-                    _ => panic!("Abort"),
-                },
-                24 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 24, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 24");
-                    }
-                    _ => panic!("Abort"),
-                },
-                25 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 25, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 25");
-                    }
-                    _ => panic!("Abort"),
-                },
-                26 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 26, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 26");
-                    }
-                    _ => panic!("Abort"),
-                },
-                27 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 27, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 27");
-                    }
-                    _ => panic!("Abort"),
-                },
-                28 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 28, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 28");
-                    }
-                    _ => panic!("Abort"),
-                },
-                29 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 29, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 29");
-                    }
-                    _ => panic!("Abort"),
-                },
-                30 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 30, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 30");
-                    }
-                    _ => panic!("Abort"),
-                },
-                31 => match mask_type {
-                    MaskType::Enable => {
-                        rvv_asm!("vsra.vi v24, v8, 31, v0.t");
-                    }
-                    MaskType::Disable => {
-                        rvv_asm!("vsra.vi v24, v8, 31");
-                    }
-                    _ => panic!("Abort"),
-                },
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(uimm5, "vsra.vi", imm, mask_type);
     }
 
     run_template_v_vi(exp_op, op, true, false, "vsra.vi");
@@ -3,6 +3,7 @@ use rand::Rng;
 use rvv_asm::rvv_asm;
 
 use ckb_std::syscalls::debug;
+use rvv_testcases::coverage::record_instruction;
 use rvv_testcases::intrinsic::{vl1r_v0, vl1r_v24, vl1r_v8, vs1r_v24, vsetvl};
 use rvv_testcases::log;
 use rvv_testcases::misc::{get_bit_in_slice, is_verbose, VLEN};
@@ -40,6 +41,7 @@ fn run(enable_mask: bool) {
 
     let vl = vsetvl(128, 16, 1) as usize;
     assert_eq!(vl, 128);
+    record_instruction("viota.m", 16, 1, enable_mask);
 
     vl1r_v0(&mask[..]);
     vl1r_v8(&vs2[..]);
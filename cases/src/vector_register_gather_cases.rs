@@ -5,6 +5,8 @@ use rvv_asm::rvv_asm;
 
 use eint::{Eint, E1024, E128, E256, E512};
 use rvv_testcases::runner::{run_template, InstructionArgsType, MaskType, RVVTestData};
+use rvv_testcases::rvv_asm_x;
+use rvv_testcases::rvv_op_i;
 
 // use rvv_testcases::log;
 // use ckb_std::syscalls::debug;
@@ -180,10 +182,10 @@ fn test_vrgather_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vrgather.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vrgather.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vrgather.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vrgather.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             };
@@ -206,109 +208,7 @@ fn test_vrgather_vi() {
     fn rvv_op(_: &[u8], rhs: &[u8], _: MaskType) {
         let imm = i64::from_le_bytes(rhs.try_into().unwrap());
 
-        unsafe {
-            match imm {
-                0 => {
-                    rvv_asm!("vrgather.vi v24, v8, 0");
-                }
-                1 => {
-                    rvv_asm!("vrgather.vi v24, v8, 1");
-                }
-                2 => {
-                    rvv_asm!("vrgather.vi v24, v8, 2");
-                }
-                3 => {
-                    rvv_asm!("vrgather.vi v24, v8, 3");
-                }
-                4 => {
-                    rvv_asm!("vrgather.vi v24, v8, 4");
-                }
-                5 => {
-                    rvv_asm!("vrgather.vi v24, v8, 5");
-                }
-                6 => {
-                    rvv_asm!("vrgather.vi v24, v8, 6");
-                }
-                7 => {
-                    rvv_asm!("vrgather.vi v24, v8, 7");
-                }
-                8 => {
-                    rvv_asm!("vrgather.vi v24, v8, 8");
-                }
-                9 => {
-                    rvv_asm!("vrgather.vi v24, v8, 9");
-                }
-                10 => {
-                    rvv_asm!("vrgather.vi v24, v8, 10");
-                }
-                11 => {
-                    rvv_asm!("vrgather.vi v24, v8, 11");
-                }
-                12 => {
-                    rvv_asm!("vrgather.vi v24, v8, 12");
-                }
-                13 => {
-                    rvv_asm!("vrgather.vi v24, v8, 13");
-                }
-                14 => {
-                    rvv_asm!("vrgather.vi v24, v8, 14");
-                }
-                15 => {
-                    rvv_asm!("vrgather.vi v24, v8, 15");
-                }
-                16 => {
-                    rvv_asm!("vrgather.vi v24, v8, 16");
-                }
-                17 => {
-                    rvv_asm!("vrgather.vi v24, v8, 17");
-                }
-                18 => {
-                    rvv_asm!("vrgather.vi v24, v8, 18");
-                }
-                19 => {
-                    rvv_asm!("vrgather.vi v24, v8, 19");
-                }
-                20 => {
-                    rvv_asm!("vrgather.vi v24, v8, 20");
-                }
-                21 => {
-                    rvv_asm!("vrgather.vi v24, v8, 21");
-                }
-                22 => {
-                    rvv_asm!("vrgather.vi v24, v8, 22");
-                }
-                23 => {
-                    rvv_asm!("vrgather.vi v24, v8, 23");
-                }
-                24 => {
-                    rvv_asm!("vrgather.vi v24, v8, 24");
-                }
-                25 => {
-                    rvv_asm!("vrgather.vi v24, v8, 25");
-                }
-                26 => {
-                    rvv_asm!("vrgather.vi v24, v8, 26");
-                }
-                27 => {
-                    rvv_asm!("vrgather.vi v24, v8, 27");
-                }
-                28 => {
-                    rvv_asm!("vrgather.vi v24, v8, 28");
-                }
-                29 => {
-                    rvv_asm!("vrgather.vi v24, v8, 29");
-                }
-                30 => {
-                    rvv_asm!("vrgather.vi v24, v8, 30");
-                }
-                31 => {
-                    rvv_asm!("vrgather.vi v24, v8, 31");
-                }
-                _ => {
-                    panic!("Abort");
-                }
-            }
-        }
+        rvv_op_i!(uimm5, "vrgather.vi", imm, unmasked);
     }
 
     run_template(
@@ -4,7 +4,9 @@ use rand::Rng;
 use rvv_asm::rvv_asm;
 
 use ckb_std::syscalls::debug;
+use rvv_testcases::rvv_asm_x;
 use rvv_testcases::{
+    coverage::record_instruction,
     intrinsic::{vl1r_v0, vl1r_v24, vl1r_v8, vs1r_v24, vsetvl},
     log,
     misc::{get_bit_in_slice, is_verbose, VLEN},
@@ -70,6 +72,8 @@ fn test_vslideup(wide: usize) {
 
     let vl = vsetvl((VLEN / wide) as u64, wide as u64, 1) as usize;
     assert_eq!(vl, VLEN / wide);
+    record_instruction("vslideup.vx", wide as u64, 1, true);
+    record_instruction("vslideup.vi", wide as u64, 1, true);
 
     expected.copy_from_slice(ref_offset.as_slice());
 
@@ -78,11 +82,7 @@ fn test_vslideup(wide: usize) {
     vl1r_v24(&expected_before[..]);
     let x: u64 = 3;
     unsafe {
-        rvv_asm!(
-            "mv t0, {}",
-            "vslideup.vx v24, v8, t0, v0.t",
-            in (reg) x
-        );
+        rvv_asm_x!("vslideup.vx v24, v8, t0, v0.t", x);
         vs1r_v24(&mut result[..]);
     }
 
@@ -135,6 +135,7 @@ fn test_vslide1up(wide: usize) {
 
     let vl = vsetvl((VLEN / wide) as u64, wide as u64, 1) as usize;
     assert_eq!(vl, VLEN / wide);
+    record_instruction("vslide1up.vx", wide as u64, 1, true);
 
     vl1r_v0(&mask[..]);
     vl1r_v8(&vs2[..]);
@@ -149,11 +150,7 @@ fn test_vslide1up(wide: usize) {
     }
 
     unsafe {
-        rvv_asm!(
-            "mv t0, {}",
-            "vslide1up.vx v24, v8, t0, v0.t",
-            in (reg) x
-        );
+        rvv_asm_x!("vslide1up.vx v24, v8, t0, v0.t", x);
         vs1r_v24(&mut result[..]);
     }
 
@@ -248,17 +245,15 @@ fn test_vslidedown(wide: usize) {
 
     let vl = vsetvl((VLEN / wide) as u64, wide as u64, 1) as usize;
     assert_eq!(vl, VLEN / wide);
+    record_instruction("vslidedown.vx", wide as u64, 1, true);
+    record_instruction("vslidedown.vi", wide as u64, 1, true);
 
     vl1r_v0(&mask[..]);
     vl1r_v8(&vs2[..]);
     vl1r_v24(&expected_before[..]);
     let x: u64 = 3;
     unsafe {
-        rvv_asm!(
-            "mv t0, {}",
-            "vslidedown.vx v24, v8, t0, v0.t",
-            in (reg) x
-        );
+        rvv_asm_x!("vslidedown.vx v24, v8, t0, v0.t", x);
         vs1r_v24(&mut result[..]);
     }
 
@@ -311,6 +306,7 @@ fn test_vslide1down(wide: usize) {
 
     let vl = vsetvl((VLEN / wide) as u64, wide as u64, 1) as usize;
     assert_eq!(vl, VLEN / wide);
+    record_instruction("vslide1down.vx", wide as u64, 1, true);
 
     vl1r_v0(&mask[..]);
     vl1r_v8(&vs2[..]);
@@ -326,11 +322,7 @@ fn test_vslide1down(wide: usize) {
     }
 
     unsafe {
-        rvv_asm!(
-            "mv t0, {}",
-            "vslide1down.vx v24, v8, t0, v0.t",
-            in (reg) x
-        );
+        rvv_asm_x!("vslide1down.vx v24, v8, t0, v0.t", x);
         vs1r_v24(&mut result[..]);
     }
 
@@ -0,0 +1,148 @@
+use alloc::vec::Vec;
+
+use eint::{E1024, E256, E512};
+
+use super::misc::{create_vtype, RvvElement, VLEN};
+use super::runner::BinaryOp;
+
+// A pure-Rust model of the architectural vector state, for comparing whole
+// instruction sequences against the hardware (see `sequence_cases`).
+// Instructions are unmasked and tail undisturbed, like `create_vtype`.
+
+const VLENB: usize = VLEN / 8;
+// widest element, E1024
+const MAX_SEW_BYTES: usize = 128;
+
+pub enum Operand {
+    Vector(usize),
+    // sign-extended to SEW
+    Scalar(u64),
+    Immediate(i64),
+}
+
+pub struct VState {
+    // v0..v31, VLEN bits each
+    pub registers: Vec<u8>,
+    pub vl: u64,
+    pub sew: u64,
+    pub lmul: i64,
+    pub vill: bool,
+    pub vxrm: u64,
+    pub vxsat: u64,
+}
+
+impl VState {
+    // vtype is invalid until the first `vsetvl`
+    pub fn new(registers: &[u8]) -> Self {
+        assert_eq!(registers.len(), 32 * VLENB);
+        VState {
+            registers: registers.to_vec(),
+            vl: 0,
+            sew: 8,
+            lmul: 1,
+            vill: true,
+            vxrm: 0,
+            vxsat: 0,
+        }
+    }
+
+    pub fn vlmax(sew: u64, lmul: i64) -> u64 {
+        if lmul > 0 {
+            VLEN as u64 * lmul as u64 / sew
+        } else {
+            VLEN as u64 / (sew * (-lmul) as u64)
+        }
+    }
+
+    pub fn vsetvl(&mut self, avl: u64, sew: u64, lmul: i64) -> u64 {
+        let vlmax = Self::vlmax(sew, lmul);
+        self.vill = vlmax == 0;
+        self.sew = sew;
+        self.lmul = lmul;
+        self.vl = if self.vill { 0 } else { avl.min(vlmax) };
+        self.vl
+    }
+
+    pub fn vtype(&self) -> u64 {
+        if self.vill {
+            1 << 63
+        } else {
+            create_vtype(self.sew, self.lmul)
+        }
+    }
+
+    pub fn register(&self, index: usize) -> &[u8] {
+        &self.registers[index * VLENB..(index + 1) * VLENB]
+    }
+
+    // element `index` of the register group starting at `reg`
+    fn element(&self, reg: usize, index: usize) -> &[u8] {
+        let n = self.sew as usize / 8;
+        let start = reg * VLENB + index * n;
+        &self.registers[start..start + n]
+    }
+
+    fn element_mut(&mut self, reg: usize, index: usize) -> &mut [u8] {
+        let n = self.sew as usize / 8;
+        let start = reg * VLENB + index * n;
+        &mut self.registers[start..start + n]
+    }
+
+    // `op vd, vs2, rhs` with the reference `O`
+    pub fn execute<O: BinaryOp>(&mut self, vd: usize, vs2: usize, rhs: Operand) {
+        self.execute_saturating::<O, O>(vd, vs2, rhs);
+    }
+
+    // Like `execute`, vxsat is set when an element of `O` differs from the
+    // wrapping op `W`, e.g. vsaddu and vadd.
+    pub fn execute_saturating<O: BinaryOp, W: BinaryOp>(
+        &mut self,
+        vd: usize,
+        vs2: usize,
+        rhs: Operand,
+    ) {
+        assert!(!self.vill);
+        let saturated = match self.sew {
+            8 => self.run::<O, W, u8>(vd, vs2, &rhs),
+            16 => self.run::<O, W, u16>(vd, vs2, &rhs),
+            32 => self.run::<O, W, u32>(vd, vs2, &rhs),
+            64 => self.run::<O, W, u64>(vd, vs2, &rhs),
+            128 => self.run::<O, W, u128>(vd, vs2, &rhs),
+            256 => self.run::<O, W, E256>(vd, vs2, &rhs),
+            512 => self.run::<O, W, E512>(vd, vs2, &rhs),
+            1024 => self.run::<O, W, E1024>(vd, vs2, &rhs),
+            _ => panic!("Invalid sew"),
+        };
+        if saturated {
+            self.vxsat = 1;
+        }
+    }
+
+    fn run<O: BinaryOp, W: BinaryOp, T: RvvElement>(
+        &mut self,
+        vd: usize,
+        vs2: usize,
+        rhs: &Operand,
+    ) -> bool {
+        let n = self.sew as usize / 8;
+        let mut saturated = false;
+        // element by element, so vd may be vs2 or vs1
+        for i in 0..self.vl as usize {
+            let l = T::from_slice(self.element(vs2, i));
+            let r = match *rhs {
+                Operand::Vector(vs1) => T::from_slice(self.element(vs1, i)),
+                Operand::Scalar(x) => T::from_i64(x as i64),
+                Operand::Immediate(imm) => T::from_i64(imm),
+            };
+            let mut result = [0u8; MAX_SEW_BYTES];
+            let mut wrapped = [0u8; MAX_SEW_BYTES];
+            T::to_slice(O::op(l, r), &mut result[..n]);
+            T::to_slice(W::op(l, r), &mut wrapped[..n]);
+            if result[..n] != wrapped[..n] {
+                saturated = true;
+            }
+            self.element_mut(vd, i).copy_from_slice(&result[..n]);
+        }
+        saturated
+    }
+}
@@ -1,7 +1,7 @@
 use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E128, E256, E512};
-use rvv_asm::rvv_asm;
 use rvv_testcases::runner::{run_template_m_vx, MaskType};
+use rvv_testcases::rvv_asm_x;
 
 fn expected_op_eq(lhs: &[u8], x: u64, result: &mut bool) {
     let sew = lhs.len() * 8;
@@ -54,10 +54,10 @@ fn test_vmseq() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmseq.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmseq.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmseq.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmseq.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -117,10 +117,10 @@ fn test_vmsne() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmsne.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmsne.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmsne.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmsne.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -180,10 +180,10 @@ fn test_vmsltu() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmsltu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmsltu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmsltu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmsltu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -247,10 +247,10 @@ fn test_vmslt() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmslt.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmslt.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmslt.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmslt.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -310,10 +310,10 @@ fn test_vmsleu() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmsleu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmsleu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmsleu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmsleu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -373,10 +373,10 @@ fn test_vmsle() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmsle.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmsle.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmsle.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmsle.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -436,10 +436,10 @@ fn test_vmsgtu() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmsgtu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmsgtu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmsgtu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmsgtu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -499,10 +499,10 @@ fn test_vmsgt() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vmsgt.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vmsgt.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vmsgt.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vmsgt.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
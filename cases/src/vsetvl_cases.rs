@@ -1,4 +1,5 @@
 use ckb_std::syscalls::debug;
+use rvv_testcases::coverage::record_instruction;
 use rvv_testcases::intrinsic_setvl::{v_setivli, v_setvl, v_setvli};
 use rvv_testcases::log;
 
@@ -22,6 +23,9 @@ pub fn test_vsetvl() {
                     );
                     panic!("Abort");
                 }
+                record_instruction("vsetvl", sew, lmul, false);
+                record_instruction("vsetvli", sew, lmul, false);
+                record_instruction("vsetivli", sew, lmul, false);
             }
         }
     }
@@ -1,6 +1,6 @@
 use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E128, E2048, E256, E512};
-use rvv_asm::rvv_asm;
+use rvv_testcases::rvv_asm_x;
 use rvv_testcases::{
     misc::{conver_to_i1024, conver_to_i2048, conver_to_i256, conver_to_i512},
     runner::{run_template_w_vx, MaskType},
@@ -67,10 +67,10 @@ fn test_vwaddu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwaddu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwaddu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwaddu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwaddu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -140,10 +140,10 @@ fn test_vwadd_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwadd.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwadd.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwadd.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwadd.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -213,10 +213,10 @@ fn test_vwsubu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwsubu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwsubu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwsubu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwsubu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -286,10 +286,10 @@ fn test_vwsub_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwsub.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwsub.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwsub.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwsub.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -359,10 +359,10 @@ fn test_vwmulu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmulu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmulu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmulu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmulu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -432,10 +432,10 @@ fn test_vwmul_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmul.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmul.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmul.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmul.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -505,10 +505,10 @@ fn test_vwmulsu_vx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwmulsu.vx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwmulsu.vx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwmulsu.vx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwmulsu.vx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
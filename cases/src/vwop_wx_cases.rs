@@ -1,7 +1,7 @@
 use core::{arch::asm, convert::TryInto};
 use eint::{Eint, E1024, E2048, E256, E512};
-use rvv_asm::rvv_asm;
 use rvv_testcases::runner::{run_template_w_wx, MaskType};
+use rvv_testcases::rvv_asm_x;
 
 fn expected_op_addu(lhs: &[u8], rhs: u64, result: &mut [u8]) {
     assert_eq!(lhs.len(), result.len());
@@ -76,10 +76,10 @@ fn test_vw_addu_wx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwaddu.wx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwaddu.wx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwaddu.wx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwaddu.wx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -161,10 +161,10 @@ fn test_vw_add_wx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwadd.wx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwadd.wx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwadd.wx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwadd.wx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -246,10 +246,10 @@ fn test_vw_subu_wx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwsubu.wx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwsubu.wx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwsubu.wx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwsubu.wx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
@@ -331,10 +331,10 @@ fn test_vw_sub_wx() {
         unsafe {
             match mask_type {
                 MaskType::Enable => {
-                    rvv_asm!("mv t0, {}", "vwsub.wx v24, v8, t0, v0.t", in (reg) x);
+                    rvv_asm_x!("vwsub.wx v24, v8, t0, v0.t", x);
                 }
                 MaskType::Disable => {
-                    rvv_asm!("mv t0, {}", "vwsub.wx v24, v8, t0", in (reg) x);
+                    rvv_asm_x!("vwsub.wx v24, v8, t0", x);
                 }
                 _ => panic!("Abort"),
             }
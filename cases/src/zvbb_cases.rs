@@ -0,0 +1,358 @@
+use core::convert::TryInto;
+use eint::{Eint, E1024, E256, E512};
+use rvv_testcases::{
+    isa::{lookup, OperandForm},
+    misc::{to_1024, to_128, to_256, to_512, to_u128, to_u16, to_u32, to_u64, to_u8},
+    runner::{
+        run_template_v_vi, run_template_v_vv, run_template_v_vx, run_template_w_vi,
+        run_template_w_vv, run_template_w_vx, MaskType,
+    },
+};
+use rvv_testcases::{raw_op, raw_op_i, raw_op_x};
+
+// vs1 of the unary instructions, funct6 and the operand forms come from
+// `INSTRUCTIONS`. rvv_asm doesn't know Zvbb, see `raw_op!`.
+const VBREV8: u32 = 0b01000;
+const VREV8: u32 = 0b01001;
+const VBREV: u32 = 0b01010;
+const VCLZ: u32 = 0b01100;
+const VCTZ: u32 = 0b01101;
+const VCPOP: u32 = 0b01110;
+
+// Only the low log2(SEW) bits are used, they are in the first 4 bytes.
+fn shift_amount(rhs: &[u8]) -> u32 {
+    let mut buf = [0u8; 4];
+    let len = rhs.len().min(4);
+    buf[..len].copy_from_slice(&rhs[..len]);
+    u32::from_le_bytes(buf)
+}
+
+// x is sign-extended to SEW
+fn scalar_to_element(x: u64, len: usize) -> [u8; 128] {
+    let mut buf = if (x as i64) < 0 {
+        [0xFFu8; 128]
+    } else {
+        [0u8; 128]
+    };
+    let n = len.min(8);
+    buf[..n].copy_from_slice(&x.to_le_bytes()[..n]);
+    buf
+}
+
+fn put_count(n: u32, result: &mut [u8]) {
+    result.fill(0);
+    let len = result.len().min(4);
+    result[..len].copy_from_slice(&n.to_le_bytes()[..len]);
+}
+
+fn expected_op_andn(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+    assert!(lhs.len() == rhs.len() && rhs.len() == result.len());
+    for i in 0..lhs.len() {
+        result[i] = lhs[i] & !rhs[i];
+    }
+}
+
+fn expected_op_andn_vx(lhs: &[u8], x: u64, result: &mut [u8]) {
+    let rhs = scalar_to_element(x, lhs.len());
+    expected_op_andn(lhs, &rhs[..lhs.len()], result);
+}
+
+fn expected_op_rol(lhs: &[u8], shamt: u32, result: &mut [u8]) {
+    assert_eq!(lhs.len(), result.len());
+    let bits = lhs.len() as u32 * 8;
+    let n = shamt % bits;
+    if n == 0 {
+        result.copy_from_slice(lhs);
+        return;
+    }
+    match lhs.len() {
+        1 => {
+            result[0] = to_u8(lhs).rotate_left(n);
+        }
+        2 => {
+            result.copy_from_slice(&to_u16(lhs).rotate_left(n).to_le_bytes());
+        }
+        4 => {
+            result.copy_from_slice(&to_u32(lhs).rotate_left(n).to_le_bytes());
+        }
+        8 => {
+            result.copy_from_slice(&to_u64(lhs).rotate_left(n).to_le_bytes());
+        }
+        16 => {
+            result.copy_from_slice(&to_u128(lhs).rotate_left(n).to_le_bytes());
+        }
+        32 => {
+            let l = to_256(lhs);
+            (l.wrapping_shl(n) | l.wrapping_shr(bits - n)).put(result);
+        }
+        64 => {
+            let l = to_512(lhs);
+            (l.wrapping_shl(n) | l.wrapping_shr(bits - n)).put(result);
+        }
+        128 => {
+            let l = to_1024(lhs);
+            (l.wrapping_shl(n) | l.wrapping_shr(bits - n)).put(result);
+        }
+        _ => {
+            panic!("Invalid sew");
+        }
+    }
+}
+
+fn expected_op_ror(lhs: &[u8], shamt: u32, result: &mut [u8]) {
+    let bits = lhs.len() as u32 * 8;
+    expected_op_rol(lhs, bits - shamt % bits, result);
+}
+
+fn expected_op_wsll(lhs: &[u8], shamt: u32, result: &mut [u8]) {
+    assert_eq!(lhs.len() * 2, result.len());
+    let n = shamt % (result.len() as u32 * 8);
+    match lhs.len() * 8 {
+        8 => {
+            let res = (to_u8(lhs) as u16).wrapping_shl(n);
+            result.copy_from_slice(&res.to_le_bytes());
+        }
+        16 => {
+            let res = (to_u16(lhs) as u32).wrapping_shl(n);
+            result.copy_from_slice(&res.to_le_bytes());
+        }
+        32 => {
+            let res = (to_u32(lhs) as u64).wrapping_shl(n);
+            result.copy_from_slice(&res.to_le_bytes());
+        }
+        64 => {
+            let res = (to_u64(lhs) as u128).wrapping_shl(n);
+            result.copy_from_slice(&res.to_le_bytes());
+        }
+        128 => {
+            E256::from(to_128(lhs)).wrapping_shl(n).put(result);
+        }
+        256 => {
+            E512::from(to_256(lhs)).wrapping_shl(n).put(result);
+        }
+        512 => {
+            E1024::from(to_512(lhs)).wrapping_shl(n).put(result);
+        }
+        sew => {
+            panic!("Invalid sew: {}", sew);
+        }
+    }
+}
+
+fn test_vandn() {
+    fn op_vv(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vandn.vv");
+        raw_op!(INSN.0, INSN.1, 16, mask_type);
+    }
+    run_template_v_vv(expected_op_andn, op_vv, true, "vandn.vv");
+
+    fn op_vx(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vandn.vx");
+        let x = u64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_x!(INSN.0, INSN.1, x, mask_type);
+    }
+    run_template_v_vx(expected_op_andn_vx, op_vx, true, "vandn.vx");
+}
+
+fn test_unary() {
+    fn exp_brev8(lhs: &[u8], _: &[u8], result: &mut [u8]) {
+        for i in 0..lhs.len() {
+            result[i] = lhs[i].reverse_bits();
+        }
+    }
+    fn op_brev8(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vbrev8.v");
+        raw_op!(INSN.0, INSN.1, VBREV8, mask_type);
+    }
+    run_template_v_vv(exp_brev8, op_brev8, true, "vbrev8.v");
+
+    fn exp_rev8(lhs: &[u8], _: &[u8], result: &mut [u8]) {
+        for i in 0..lhs.len() {
+            result[i] = lhs[lhs.len() - 1 - i];
+        }
+    }
+    fn op_rev8(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vrev8.v");
+        raw_op!(INSN.0, INSN.1, VREV8, mask_type);
+    }
+    run_template_v_vv(exp_rev8, op_rev8, true, "vrev8.v");
+
+    fn exp_brev(lhs: &[u8], _: &[u8], result: &mut [u8]) {
+        for i in 0..lhs.len() {
+            result[i] = lhs[lhs.len() - 1 - i].reverse_bits();
+        }
+    }
+    fn op_brev(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vbrev.v");
+        raw_op!(INSN.0, INSN.1, VBREV, mask_type);
+    }
+    run_template_v_vv(exp_brev, op_brev, true, "vbrev.v");
+
+    fn exp_clz(lhs: &[u8], _: &[u8], result: &mut [u8]) {
+        let mut n = 0;
+        for b in lhs.iter().rev() {
+            n += b.leading_zeros();
+            if *b != 0 {
+                break;
+            }
+        }
+        put_count(n, result);
+    }
+    fn op_clz(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vclz.v");
+        raw_op!(INSN.0, INSN.1, VCLZ, mask_type);
+    }
+    run_template_v_vv(exp_clz, op_clz, true, "vclz.v");
+
+    fn exp_ctz(lhs: &[u8], _: &[u8], result: &mut [u8]) {
+        let mut n = 0;
+        for b in lhs.iter() {
+            n += b.trailing_zeros();
+            if *b != 0 {
+                break;
+            }
+        }
+        put_count(n, result);
+    }
+    fn op_ctz(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vctz.v");
+        raw_op!(INSN.0, INSN.1, VCTZ, mask_type);
+    }
+    run_template_v_vv(exp_ctz, op_ctz, true, "vctz.v");
+
+    fn exp_cpop(lhs: &[u8], _: &[u8], result: &mut [u8]) {
+        put_count(lhs.iter().map(|b| b.count_ones()).sum(), result);
+    }
+    fn op_cpop(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vcpop.v");
+        raw_op!(INSN.0, INSN.1, VCPOP, mask_type);
+    }
+    run_template_v_vv(exp_cpop, op_cpop, true, "vcpop.v");
+}
+
+fn test_vrol() {
+    fn exp_vv(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+        expected_op_rol(lhs, shift_amount(rhs), result);
+    }
+    fn op_vv(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vrol.vv");
+        raw_op!(INSN.0, INSN.1, 16, mask_type);
+    }
+    run_template_v_vv(exp_vv, op_vv, true, "vrol.vv");
+
+    fn exp_vx(lhs: &[u8], x: u64, result: &mut [u8]) {
+        expected_op_rol(lhs, x as u32, result);
+    }
+    fn op_vx(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vrol.vx");
+        let x = u64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_x!(INSN.0, INSN.1, x, mask_type);
+    }
+    run_template_v_vx(exp_vx, op_vx, true, "vrol.vx");
+}
+
+fn test_vror() {
+    fn exp_vv(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+        expected_op_ror(lhs, shift_amount(rhs), result);
+    }
+    fn op_vv(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vror.vv");
+        raw_op!(INSN.0, INSN.1, 16, mask_type);
+    }
+    run_template_v_vv(exp_vv, op_vv, true, "vror.vv");
+
+    fn exp_vx(lhs: &[u8], x: u64, result: &mut [u8]) {
+        expected_op_ror(lhs, x as u32, result);
+    }
+    fn op_vx(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vror.vx");
+        let x = u64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_x!(INSN.0, INSN.1, x, mask_type);
+    }
+    run_template_v_vx(exp_vx, op_vx, true, "vror.vx");
+
+    fn exp_vi(lhs: &[u8], imm: i64, result: &mut [u8]) {
+        expected_op_ror(lhs, imm as u32, result);
+    }
+    fn op_vi(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vror.vi");
+        let imm = i64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_i!(
+            INSN.0,
+            imm,
+            mask_type,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+                23, 24, 25, 26, 27, 28, 29, 30, 31
+            ]
+        );
+    }
+    run_template_v_vi(exp_vi, op_vi, true, false, "vror.vi");
+
+    // the runner only generates immediates 0..31, this one adds 32 to cover
+    // the 6th bit
+    fn exp_vi_high(lhs: &[u8], imm: i64, result: &mut [u8]) {
+        expected_op_ror(lhs, imm as u32 + 32, result);
+    }
+    fn op_vi_high(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vror.vi");
+        let imm = i64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_i!(
+            INSN.0,
+            imm + 32,
+            mask_type,
+            [
+                32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52,
+                53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63
+            ]
+        );
+    }
+    run_template_v_vi(exp_vi_high, op_vi_high, true, false, "vror.vi");
+}
+
+fn test_vwsll() {
+    fn exp_vv(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+        expected_op_wsll(lhs, shift_amount(rhs), result);
+    }
+    fn op_vv(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vwsll.vv");
+        raw_op!(INSN.0, INSN.1, 16, mask_type);
+    }
+    run_template_w_vv(exp_vv, op_vv, true, "vwsll.vv");
+
+    fn exp_vx(lhs: &[u8], x: u64, result: &mut [u8]) {
+        expected_op_wsll(lhs, x as u32, result);
+    }
+    fn op_vx(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vwsll.vx");
+        let x = u64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_x!(INSN.0, INSN.1, x, mask_type);
+    }
+    run_template_w_vx(exp_vx, op_vx, true, "vwsll.vx");
+
+    fn exp_vi(lhs: &[u8], imm: i64, result: &mut [u8]) {
+        expected_op_wsll(lhs, imm as u32, result);
+    }
+    fn op_vi(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vwsll.vi");
+        let imm = i64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_i!(
+            INSN.0,
+            imm,
+            mask_type,
+            [
+                0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+                23, 24, 25, 26, 27, 28, 29, 30, 31
+            ]
+        );
+    }
+    run_template_w_vi(exp_vi, op_vi, true, "vwsll.vi");
+}
+
+pub fn test_zvbb() {
+    test_vandn();
+    test_unary();
+    test_vrol();
+    test_vror();
+    test_vwsll();
+}
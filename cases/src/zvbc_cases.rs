@@ -0,0 +1,86 @@
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use eint::{Eint, E1024};
+use rvv_testcases::{
+    isa::{lookup, OperandForm},
+    runner::{run_template_v_vv, run_template_v_vx, MaskType},
+};
+use rvv_testcases::{raw_op, raw_op_x};
+
+// Carry-less product of two SEW-bit elements, 2 * SEW bits. Official Zvbc only
+// has SEW = 64, ckb-vm accepts every SEW.
+fn clmul(lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+    assert_eq!(lhs.len(), rhs.len());
+    let mut product: Vec<u8> = Vec::new();
+    product.resize(lhs.len() * 2, 0);
+    for i in 0..rhs.len() * 8 {
+        if (rhs[i / 8] >> (i % 8)) & 1 == 0 {
+            continue;
+        }
+        // product ^= lhs << i
+        for j in 0..lhs.len() {
+            let v = (lhs[j] as u16) << (i % 8);
+            product[i / 8 + j] ^= v as u8;
+            product[i / 8 + j + 1] ^= (v >> 8) as u8;
+        }
+    }
+    product
+}
+
+fn expected_op_clmul(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+    assert_eq!(lhs.len(), result.len());
+    result.copy_from_slice(&clmul(lhs, rhs)[..lhs.len()]);
+}
+
+fn expected_op_clmulh(lhs: &[u8], rhs: &[u8], result: &mut [u8]) {
+    assert_eq!(lhs.len(), result.len());
+    result.copy_from_slice(&clmul(lhs, rhs)[lhs.len()..]);
+}
+
+// x is sign-extended to SEW
+fn scalar(x: u64, len: usize) -> Vec<u8> {
+    let mut buf = [0u8; 128];
+    E1024::from(x as i64).put(&mut buf);
+    buf[..len].to_vec()
+}
+
+fn test_vclmul() {
+    fn op_vv(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vclmul.vv");
+        raw_op!(INSN.0, INSN.1, 16, mask_type);
+    }
+    run_template_v_vv(expected_op_clmul, op_vv, true, "vclmul.vv");
+
+    fn exp_vx(lhs: &[u8], x: u64, result: &mut [u8]) {
+        expected_op_clmul(lhs, &scalar(x, lhs.len()), result);
+    }
+    fn op_vx(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vclmul.vx");
+        let x = u64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_x!(INSN.0, INSN.1, x, mask_type);
+    }
+    run_template_v_vx(exp_vx, op_vx, true, "vclmul.vx");
+}
+
+fn test_vclmulh() {
+    fn op_vv(_: &[u8], _: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vclmulh.vv");
+        raw_op!(INSN.0, INSN.1, 16, mask_type);
+    }
+    run_template_v_vv(expected_op_clmulh, op_vv, true, "vclmulh.vv");
+
+    fn exp_vx(lhs: &[u8], x: u64, result: &mut [u8]) {
+        expected_op_clmulh(lhs, &scalar(x, lhs.len()), result);
+    }
+    fn op_vx(_: &[u8], rhs: &[u8], mask_type: MaskType) {
+        const INSN: (u8, OperandForm) = lookup("vclmulh.vx");
+        let x = u64::from_le_bytes(rhs.try_into().unwrap());
+        raw_op_x!(INSN.0, INSN.1, x, mask_type);
+    }
+    run_template_v_vx(exp_vx, op_vx, true, "vclmulh.vx");
+}
+
+pub fn test_zvbc() {
+    test_vclmul();
+    test_vclmulh();
+}
@@ -0,0 +1,278 @@
+mod vm;
+
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::fs;
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use ckb_vm::Bytes;
+
+use vm::{Exit, Outcome};
+
+const DEFAULT_BIN: &str = "cases/target/riscv64imac-unknown-none-elf/release/rvv-testcases";
+// the cap `make run` gives ckb-debugger. Here it caps the instruction count,
+// which is lower than ckb-debugger's cycles for the same run.
+const DEFAULT_MAX_CYCLES: u64 = 1_000_000_000;
+// cycles for listing the cases, nothing runs
+const LIST_CYCLES: u64 = 100_000_000;
+const FAULT_CASE: &str = "memory_boundary_cases::test_memory_end_fault";
+// what test_memory_end_fault puts below the memory end before the store
+const FAULT_MARKER: u8 = 0xA5;
+
+struct Options {
+    bin: String,
+    jobs: usize,
+    max_cycles: u64,
+    // per case, e.g. --cycles=load_store_cases::test_load_store=2000000000
+    case_cycles: HashMap<String, u64>,
+    pattern: Option<String>,
+    // passed to every case, e.g. --full or --seed=1
+    guest_args: Vec<String>,
+    // --fault=load or --fault=store, only runs FAULT_CASE
+    fault: Option<String>,
+}
+
+fn usage() -> ! {
+    eprintln!(
+        "usage: rvv-driver [--bin=ELF] [--jobs=N] [--max-cycles=N] [--cycles=CASE=N]... \
+         [--case=PATTERN] [--fault=load|store] [-- GUEST_ARGS...]\n\
+         cycles are instruction counts (every instruction costs 1), not ckb-debugger's cycles"
+    );
+    process::exit(2);
+}
+
+fn parse_number(value: &str) -> u64 {
+    value
+        .replace('_', "")
+        .parse::<u64>()
+        .unwrap_or_else(|_| usage())
+}
+
+fn parse_options() -> Options {
+    let mut options = Options {
+        bin: DEFAULT_BIN.to_string(),
+        jobs: thread::available_parallelism().map_or(1, |n| n.get()),
+        max_cycles: DEFAULT_MAX_CYCLES,
+        case_cycles: HashMap::new(),
+        pattern: None,
+        guest_args: Vec::new(),
+        fault: None,
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--" {
+            options.guest_args = args.collect();
+            break;
+        }
+        let (key, value) = match arg.split_once('=') {
+            Some(kv) => kv,
+            None => usage(),
+        };
+        match key {
+            "--bin" => options.bin = value.to_string(),
+            "--jobs" => options.jobs = parse_number(value).max(1) as usize,
+            "--max-cycles" => options.max_cycles = parse_number(value),
+            "--cycles" => match value.rsplit_once('=') {
+                Some((case, n)) => {
+                    options
+                        .case_cycles
+                        .insert(case.to_string(), parse_number(n));
+                }
+                None => usage(),
+            },
+            "--case" => options.pattern = Some(value.to_string()),
+            "--fault" => match value {
+                "load" | "store" => options.fault = Some(value.to_string()),
+                _ => usage(),
+            },
+            _ => usage(),
+        }
+    }
+    options
+}
+
+// The names printed by `--list`, in the order they are registered
+fn list_cases(program: &Bytes, options: &Options) -> Vec<String> {
+    let mut args = vec!["--list".to_string()];
+    if let Some(pattern) = &options.pattern {
+        args.push(format!("--case={}", pattern));
+    }
+    let outcome = vm::run(program, &args, LIST_CYCLES);
+    if !matches!(outcome.exit, Exit::Code(0)) {
+        eprintln!("listing the cases failed:");
+        for line in outcome.output.iter() {
+            eprintln!("{}", line);
+        }
+        process::exit(1);
+    }
+    outcome
+        .output
+        .iter()
+        .filter_map(|line| line.strip_prefix("case "))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+struct Report {
+    case: String,
+    outcome: Outcome,
+    elapsed: Duration,
+}
+
+impl Report {
+    fn passed(&self) -> bool {
+        matches!(self.outcome.exit, Exit::Code(0))
+    }
+
+    fn status(&self) -> String {
+        match &self.outcome.exit {
+            Exit::Code(0) => "ok".to_string(),
+            Exit::Code(code) => format!("FAILED (exit code {})", code),
+            Exit::CyclesExceeded => "FAILED (cycles exceeded)".to_string(),
+            Exit::Error(e) => format!("FAILED ({})", e),
+        }
+    }
+}
+
+// The access straddling the memory end must stop the VM with a memory out of
+// bounds error. A store may write the elements inside the memory or nothing,
+// the 8 bytes there must hold the first 8 elements (0, 1, .. 7) or the marker.
+fn run_fault(program: &Bytes, fault: &str, max_cycles: u64) -> bool {
+    let args = vec![
+        format!("--case={}", FAULT_CASE),
+        "--exact".to_string(),
+        format!("--fault={}", fault),
+    ];
+    let outcome = vm::run(program, &args, max_cycles);
+    for line in outcome.output.iter() {
+        println!("{}", line);
+    }
+    match &outcome.exit {
+        Exit::Error(e) if e.contains("MemOutOfBound") => {}
+        Exit::Error(e) => {
+            println!("fault {}: FAILED, expected MemOutOfBound, got {}", fault, e);
+            return false;
+        }
+        _ => {
+            println!("fault {}: FAILED, the VM didn't stop with an error", fault);
+            return false;
+        }
+    }
+    if fault == "store" {
+        let elements: Vec<u8> = (0..8).collect();
+        let written = if outcome.memory_tail == elements {
+            "the elements inside the memory"
+        } else if outcome.memory_tail.iter().all(|b| *b == FAULT_MARKER) {
+            "nothing"
+        } else {
+            println!(
+                "fault store: FAILED, the memory end holds {:02X?}",
+                outcome.memory_tail
+            );
+            return false;
+        };
+        println!("fault store: ok, wrote {}", written);
+    } else {
+        println!("fault load: ok");
+    }
+    true
+}
+
+// Every case runs in its own machine, `jobs` machines at a time
+fn run_cases(program: Bytes, cases: Vec<String>, options: &Options) -> Vec<Report> {
+    let queue = Arc::new(Mutex::new(cases.into_iter().collect::<VecDeque<_>>()));
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let workers: Vec<_> = (0..options.jobs)
+        .map(|_| {
+            let queue = queue.clone();
+            let reports = reports.clone();
+            let program = program.clone();
+            let guest_args = options.guest_args.clone();
+            let max_cycles = options.max_cycles;
+            let case_cycles = options.case_cycles.clone();
+            thread::spawn(move || loop {
+                let case = match queue.lock().unwrap().pop_front() {
+                    Some(case) => case,
+                    None => break,
+                };
+                let mut args = vec![format!("--case={}", case), "--exact".to_string()];
+                args.extend(guest_args.iter().cloned());
+                let cycles = case_cycles.get(&case).copied().unwrap_or(max_cycles);
+                let start = Instant::now();
+                let outcome = vm::run(&program, &args, cycles);
+                let report = Report {
+                    case,
+                    outcome,
+                    elapsed: start.elapsed(),
+                };
+                println!("test {} ... {}", report.case, report.status());
+                reports.lock().unwrap().push(report);
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+    let reports = Arc::try_unwrap(reports).ok().unwrap();
+    reports.into_inner().unwrap()
+}
+
+fn print_report(cases: &[String], mut reports: Vec<Report>) -> bool {
+    // registration order, not completion order
+    reports.sort_by_key(|r| cases.iter().position(|c| *c == r.case));
+    let failed: Vec<&Report> = reports.iter().filter(|r| !r.passed()).collect();
+    for report in failed.iter() {
+        println!();
+        println!("---- {} output ----", report.case);
+        for line in report.outcome.output.iter() {
+            println!("{}", line);
+        }
+    }
+    println!();
+    println!("{:<80} {:>14} {:>10}", "case", "cycles", "seconds");
+    for report in reports.iter() {
+        println!(
+            "{:<80} {:>14} {:>10.2} {}",
+            report.case,
+            report.outcome.cycles,
+            report.elapsed.as_secs_f64(),
+            report.status()
+        );
+    }
+    let cycles: u64 = reports.iter().map(|r| r.outcome.cycles).sum();
+    println!();
+    println!(
+        "test result: {}. {} passed; {} failed; {} cycles (instructions)",
+        if failed.is_empty() { "ok" } else { "FAILED" },
+        reports.len() - failed.len(),
+        failed.len(),
+        cycles
+    );
+    failed.is_empty()
+}
+
+fn main() {
+    let options = parse_options();
+    let program = match fs::read(&options.bin) {
+        Ok(data) => Bytes::from(data),
+        Err(e) => {
+            eprintln!("can't read {}: {}", options.bin, e);
+            process::exit(1);
+        }
+    };
+    if let Some(fault) = &options.fault {
+        if !run_fault(&program, fault, options.max_cycles) {
+            process::exit(1);
+        }
+        return;
+    }
+    let cases = list_cases(&program, &options);
+    println!("running {} cases with {} jobs", cases.len(), options.jobs);
+    let reports = run_cases(program, cases.clone(), &options);
+    if !print_report(&cases, reports) {
+        process::exit(1);
+    }
+}
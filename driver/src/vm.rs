@@ -0,0 +1,115 @@
+use std::sync::{Arc, Mutex};
+
+use ckb_vm::machine::{DefaultCoreMachine, DefaultMachineBuilder, VERSION1};
+use ckb_vm::memory::sparse::SparseMemory;
+use ckb_vm::memory::wxorx::WXorXMemory;
+use ckb_vm::registers::{A0, A7};
+use ckb_vm::{
+    Bytes, CoreMachine, Error, Memory, Register, SupportMachine, Syscalls, ISA_B, ISA_IMC, ISA_MOP,
+    ISA_V, RISCV_MAX_MEMORY,
+};
+
+// ckb_std::syscalls::debug
+const DEBUG_SYSCALL: u64 = 2177;
+// ckb_std::syscalls::current_cycles, used by --cycles-budget. Answers the
+// instruction count, see `run`.
+const CURRENT_CYCLES_SYSCALL: u64 = 2042;
+
+// Collects the lines printed with the debug syscall and answers current_cycles
+struct Debug {
+    lines: Arc<Mutex<Vec<String>>>,
+}
+
+impl<Mac: SupportMachine> Syscalls<Mac> for Debug {
+    fn initialize(&mut self, _machine: &mut Mac) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn ecall(&mut self, machine: &mut Mac) -> Result<bool, Error> {
+        match machine.registers()[A7].to_u64() {
+            DEBUG_SYSCALL => {}
+            CURRENT_CYCLES_SYSCALL => {
+                let cycles = machine.cycles();
+                machine.set_register(A0, Mac::REG::from_u64(cycles));
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        }
+        let mut addr = machine.registers()[A0].to_u64();
+        let mut buffer = Vec::new();
+        loop {
+            let byte = machine
+                .memory_mut()
+                .load8(&Mac::REG::from_u64(addr))?
+                .to_u8();
+            if byte == 0 {
+                break;
+            }
+            buffer.push(byte);
+            addr += 1;
+        }
+        let line = String::from_utf8_lossy(&buffer).to_string();
+        self.lines.lock().unwrap().push(line);
+        Ok(true)
+    }
+}
+
+pub enum Exit {
+    Code(i8),
+    CyclesExceeded,
+    // anything else stopping the VM, e.g. a memory out of bounds access
+    Error(String),
+}
+
+pub struct Outcome {
+    pub exit: Exit,
+    pub cycles: u64,
+    pub output: Vec<String>,
+    // the last 8 bytes of memory after the run, see `run_fault`
+    pub memory_tail: Vec<u8>,
+}
+
+// Runs the ELF in a fresh machine, `args` are passed to the guest as argv.
+pub fn run(program: &Bytes, args: &[String], max_cycles: u64) -> Outcome {
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let core = DefaultCoreMachine::<u64, WXorXMemory<SparseMemory<u64>>>::new(
+        ISA_IMC | ISA_B | ISA_MOP | ISA_V,
+        VERSION1,
+        max_cycles,
+    );
+    // Every instruction costs 1 cycle, so the cycles here (and what the guest's
+    // --cycles-budget sees through current_cycles) are instruction counts, lower
+    // than ckb-debugger's, which weighs loads, branches, mul/div and ecall more.
+    let mut machine = DefaultMachineBuilder::new(core)
+        .instruction_cycle_func(Box::new(|_| 1))
+        .syscall(Box::new(Debug {
+            lines: lines.clone(),
+        }))
+        .build();
+    let mut argv = vec![Bytes::from("rvv-testcases")];
+    argv.extend(args.iter().map(|a| Bytes::from(a.clone())));
+    let exit = match machine
+        .load_program(program, &argv)
+        .and_then(|_| machine.run())
+    {
+        Ok(code) => Exit::Code(code),
+        Err(Error::CyclesExceeded) => Exit::CyclesExceeded,
+        Err(e) => Exit::Error(format!("{:?}", e)),
+    };
+    let cycles = machine.cycles();
+    let output = lines.lock().unwrap().clone();
+    let memory_tail = (RISCV_MAX_MEMORY as u64 - 8..RISCV_MAX_MEMORY as u64)
+        .map(|addr| {
+            machine
+                .memory_mut()
+                .load8(&addr)
+                .map_or(0, |byte| byte.to_u8())
+        })
+        .collect();
+    Outcome {
+        exit,
+        cycles,
+        output,
+        memory_tail,
+    }
+}
@@ -0,0 +1,58 @@
+// Differential testing of ckb-vm's interpreter against its assembly machine,
+// see fuzz_targets/differential.rs
+
+mod machine;
+mod program;
+
+use ckb_vm::Bytes;
+
+pub use program::{generate, Program};
+
+// Panics, with the program listing, if the execution modes disagree
+pub fn check(data: &[u8]) {
+    let program = generate(data);
+    let elf = Bytes::from(program.elf);
+    let interpreter = machine::run_interpreter(&elf);
+    let asm = machine::run_asm(&elf);
+    if interpreter != asm {
+        let mut diff = Vec::new();
+        if interpreter.exit != asm.exit {
+            diff.push(format!(
+                "exit: {:?} (interpreter) {:?} (asm)",
+                interpreter.exit, asm.exit
+            ));
+        }
+        if interpreter.cycles != asm.cycles {
+            diff.push(format!(
+                "cycles: {} (interpreter) {} (asm)",
+                interpreter.cycles, asm.cycles
+            ));
+        }
+        for (i, (a, b)) in interpreter
+            .registers
+            .iter()
+            .zip(asm.registers.iter())
+            .enumerate()
+        {
+            if a != b {
+                diff.push(format!("x{}: {:#x} (interpreter) {:#x} (asm)", i, a, b));
+            }
+        }
+        if let Some(offset) = interpreter
+            .memory
+            .iter()
+            .zip(asm.memory.iter())
+            .position(|(a, b)| a != b)
+        {
+            diff.push(format!(
+                "memory differs from {:#x}",
+                program::DATA_ADDR + offset as u64
+            ));
+        }
+        panic!(
+            "execution modes disagree:\n{}\nprogram:\n{}",
+            diff.join("\n"),
+            program.listing.join("\n")
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use ckb_vm::machine::asm::{AsmCoreMachine, AsmMachine};
+use ckb_vm::machine::{DefaultCoreMachine, DefaultMachineBuilder, VERSION1};
+use ckb_vm::memory::sparse::SparseMemory;
+use ckb_vm::memory::wxorx::WXorXMemory;
+use ckb_vm::{Bytes, Error, Memory, Register, SupportMachine, ISA_B, ISA_IMC, ISA_MOP, ISA_V};
+
+use crate::program::{DATA_ADDR, DATA_SIZE};
+
+const ISA: u8 = ISA_IMC | ISA_B | ISA_MOP | ISA_V;
+const MAX_CYCLES: u64 = 10_000_000;
+
+// Everything the two execution modes must agree on
+#[derive(Debug, PartialEq)]
+pub struct State {
+    pub exit: Result<i8, String>,
+    pub cycles: u64,
+    pub registers: Vec<u64>,
+    // the data segment, including the stored vector registers, vl, vtype
+    // and vxsat
+    pub memory: Vec<u8>,
+}
+
+fn snapshot<M: SupportMachine>(machine: &mut M, exit: Result<i8, Error>) -> State {
+    let registers = machine.registers().iter().map(|r| r.to_u64()).collect();
+    let mut memory = Vec::with_capacity(DATA_SIZE as usize);
+    for addr in DATA_ADDR..DATA_ADDR + DATA_SIZE {
+        let byte = machine
+            .memory_mut()
+            .load8(&M::REG::from_u64(addr))
+            .map_or(0, |b| b.to_u8());
+        memory.push(byte);
+    }
+    State {
+        exit: exit.map_err(|e| format!("{:?}", e)),
+        cycles: machine.cycles(),
+        registers,
+        memory,
+    }
+}
+
+pub fn run_interpreter(elf: &Bytes) -> State {
+    let core =
+        DefaultCoreMachine::<u64, WXorXMemory<SparseMemory<u64>>>::new(ISA, VERSION1, MAX_CYCLES);
+    let mut machine = DefaultMachineBuilder::new(core)
+        .instruction_cycle_func(Box::new(|_| 1))
+        .build();
+    let exit = machine.load_program(elf, &[]).and_then(|_| machine.run());
+    snapshot(&mut machine, exit)
+}
+
+pub fn run_asm(elf: &Bytes) -> State {
+    let core = AsmCoreMachine::new(ISA, VERSION1, MAX_CYCLES);
+    let core = DefaultMachineBuilder::new(core)
+        .instruction_cycle_func(Box::new(|_| 1))
+        .build();
+    let mut machine = AsmMachine::new(core, None);
+    let exit = machine.load_program(elf, &[]).and_then(|_| machine.run());
+    snapshot(&mut machine.machine, exit)
+}
@@ -0,0 +1,376 @@
+// Turns fuzz input into a program: a prologue loading the initial state, a
+// short sequence of valid RVV instructions and an epilogue storing the state
+// to memory, packed into a minimal ELF.
+
+pub const CODE_ADDR: u64 = 0x1_0000;
+pub const DATA_ADDR: u64 = 0x10_0000;
+// a1..a5, loaded with `ld`
+const SCALARS_ADDR: u64 = DATA_ADDR;
+// source of vle, destination of vse, at most 8 * VLEN / 8 bytes per access
+const SCRATCH_ADDR: u64 = DATA_ADDR + 0x1000;
+// initial v0..v31
+const VREGS_ADDR: u64 = DATA_ADDR + 0x2000;
+// v0..v31 followed by vl, vtype and vxsat
+pub const DUMP_ADDR: u64 = DATA_ADDR + 0x4000;
+pub const DATA_SIZE: u64 = 0x7000;
+
+const ELEN: u64 = 1024;
+const MAX_INSTRUCTIONS: usize = 64;
+
+// scalar registers
+const ZERO: u32 = 0;
+const T1: u32 = 6;
+const T2: u32 = 7;
+const A0: u32 = 10;
+const A6: u32 = 16;
+const A7: u32 = 17;
+const T3: u32 = 28;
+const T4: u32 = 29;
+const T5: u32 = 30;
+
+const CSR_VXSAT: u32 = 0x009;
+const CSR_VXRM: u32 = 0x00A;
+const CSR_VL: u32 = 0xC20;
+const CSR_VTYPE: u32 = 0xC21;
+
+// both vv and vx
+const BINARY_OPS: [&str; 31] = [
+    "vadd", "vsub", "vand", "vor", "vxor", "vminu", "vmin", "vmaxu", "vmax", "vmul", "vmulh",
+    "vmulhu", "vmulhsu", "vdivu", "vdiv", "vremu", "vrem", "vsll", "vsrl", "vsra", "vsaddu",
+    "vsadd", "vssubu", "vssub", "vaaddu", "vaadd", "vasubu", "vasub", "vsmul", "vssrl", "vssra",
+];
+// (op, unsigned immediate)
+const IMMEDIATE_OPS: [(&str, bool); 12] = [
+    ("vadd", false),
+    ("vrsub", false),
+    ("vand", false),
+    ("vor", false),
+    ("vxor", false),
+    ("vsaddu", false),
+    ("vsadd", false),
+    ("vsll", true),
+    ("vsrl", true),
+    ("vsra", true),
+    ("vssrl", true),
+    ("vssra", true),
+];
+const COMPARE_OPS: [&str; 6] = ["vmseq", "vmsne", "vmsltu", "vmslt", "vmsleu", "vmsle"];
+const REDUCTION_OPS: [&str; 8] = [
+    "vredsum", "vredand", "vredor", "vredxor", "vredminu", "vredmin", "vredmaxu", "vredmax",
+];
+const MASK_OPS: [&str; 6] = ["vmand", "vmor", "vmxor", "vmnand", "vmnor", "vmxnor"];
+const SEWS: [u64; 8] = [8, 16, 32, 64, 128, 256, 512, 1024];
+// (name, numerator, denominator)
+const LMULS: [(&str, u64, u64); 7] = [
+    ("mf8", 1, 8),
+    ("mf4", 1, 4),
+    ("mf2", 1, 2),
+    ("m1", 1, 1),
+    ("m2", 2, 1),
+    ("m4", 4, 1),
+    ("m8", 8, 1),
+];
+
+fn i_type(opcode: u32, funct3: u32, rd: u32, rs1: u32, imm: i32) -> u32 {
+    ((imm as u32 & 0xFFF) << 20) | (rs1 << 15) | (funct3 << 12) | (rd << 7) | opcode
+}
+
+fn addi(rd: u32, rs1: u32, imm: i32) -> u32 {
+    i_type(0x13, 0, rd, rs1, imm)
+}
+
+fn lui(rd: u32, addr: u64) -> u32 {
+    assert_eq!(addr & 0xFFF, 0);
+    (addr as u32 & 0xFFFF_F000) | (rd << 7) | 0x37
+}
+
+fn ld(rd: u32, rs1: u32, imm: i32) -> u32 {
+    i_type(0x03, 0b011, rd, rs1, imm)
+}
+
+fn sd(rs2: u32, rs1: u32, imm: i32) -> u32 {
+    let imm = imm as u32;
+    ((imm >> 5 & 0x7F) << 25)
+        | (rs2 << 20)
+        | (rs1 << 15)
+        | (0b011 << 12)
+        | ((imm & 0x1F) << 7)
+        | 0x23
+}
+
+fn csrr(rd: u32, csr: u32) -> u32 {
+    (csr << 20) | (0b010 << 12) | (rd << 7) | 0x73
+}
+
+fn csrwi(csr: u32, uimm: u32) -> u32 {
+    (csr << 20) | (uimm << 15) | (0b101 << 12) | 0x73
+}
+
+const ECALL: u32 = 0x73;
+
+// Reads the fuzz input, zeros once it's used up
+struct Input<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Input<'a> {
+    fn byte(&mut self) -> u8 {
+        match self.data.split_first() {
+            Some((b, rest)) => {
+                self.data = rest;
+                *b
+            }
+            None => 0,
+        }
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        self.byte() as usize % n
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+}
+
+pub struct Program {
+    pub elf: Vec<u8>,
+    // the generated part, to reproduce a failure
+    pub listing: Vec<String>,
+}
+
+struct Generator {
+    code: Vec<u32>,
+    listing: Vec<String>,
+    sew: u64,
+    // registers per group, 1 for fractional LMUL
+    group: u32,
+}
+
+impl Generator {
+    fn vector(&mut self, text: String) {
+        if let Some(word) = rvv_encode::encode(&text, false).ok().flatten() {
+            self.code.push(word);
+            self.listing.push(text);
+        }
+    }
+
+    fn scalar(&mut self, word: u32, text: String) {
+        self.code.push(word);
+        self.listing.push(text);
+    }
+
+    // a register group aligned to LMUL
+    fn group_reg(&self, input: &mut Input) -> u32 {
+        (input.below(32) as u32) & !(self.group - 1)
+    }
+
+    // ", v0.t" unless vd is v0
+    fn mask(&self, input: &mut Input, vd: u32) -> &'static str {
+        if vd != 0 && input.byte() & 1 == 1 {
+            ", v0.t"
+        } else {
+            ""
+        }
+    }
+
+    fn vsetvli(&mut self, input: &mut Input) {
+        let sew = SEWS[input.below(SEWS.len())];
+        let (lmul, num, den) = LMULS[input.below(LMULS.len())];
+        // SEW <= LMUL * ELEN, otherwise vill
+        if sew * den > ELEN * num {
+            return;
+        }
+        let avl = (input.byte() as i32) << 3 | input.below(8) as i32;
+        let tail = if input.byte() & 1 == 1 { "ta" } else { "tu" };
+        let mask = if input.byte() & 1 == 1 { "ma" } else { "mu" };
+        self.scalar(addi(T1, ZERO, avl), format!("addi t1, zero, {}", avl));
+        self.vector(format!(
+            "vsetvli t0, t1, e{}, {}, {}, {}",
+            sew, lmul, tail, mask
+        ));
+        self.sew = sew;
+        self.group = num as u32;
+    }
+
+    fn instruction(&mut self, input: &mut Input) {
+        match input.below(10) {
+            0 => self.vsetvli(input),
+            1 | 2 => {
+                let op = BINARY_OPS[input.below(BINARY_OPS.len())];
+                let (vd, vs2, vs1) = (
+                    self.group_reg(input),
+                    self.group_reg(input),
+                    self.group_reg(input),
+                );
+                let mask = self.mask(input, vd);
+                self.vector(format!("{}.vv v{}, v{}, v{}{}", op, vd, vs2, vs1, mask));
+            }
+            3 => {
+                let op = BINARY_OPS[input.below(BINARY_OPS.len())];
+                let (vd, vs2) = (self.group_reg(input), self.group_reg(input));
+                let rs1 = ["a1", "a2", "a3", "a4", "a5"][input.below(5)];
+                let mask = self.mask(input, vd);
+                self.vector(format!("{}.vx v{}, v{}, {}{}", op, vd, vs2, rs1, mask));
+            }
+            4 => {
+                let (op, unsigned) = IMMEDIATE_OPS[input.below(IMMEDIATE_OPS.len())];
+                let (vd, vs2) = (self.group_reg(input), self.group_reg(input));
+                let imm = if unsigned {
+                    input.below(32) as i32
+                } else {
+                    input.below(32) as i32 - 16
+                };
+                let mask = self.mask(input, vd);
+                self.vector(format!("{}.vi v{}, v{}, {}{}", op, vd, vs2, imm, mask));
+            }
+            5 => {
+                // vd must not overlap the sources, their EEW differs
+                let op = COMPARE_OPS[input.below(COMPARE_OPS.len())];
+                let (vs2, vs1) = (self.group_reg(input), self.group_reg(input));
+                let vd = input.below(32) as u32;
+                let overlaps = |vs: u32| vd >= vs && vd < vs + self.group;
+                if overlaps(vs2) || overlaps(vs1) {
+                    return;
+                }
+                let mask = self.mask(input, vd);
+                self.vector(format!("{}.vv v{}, v{}, v{}{}", op, vd, vs2, vs1, mask));
+            }
+            6 => {
+                let op = REDUCTION_OPS[input.below(REDUCTION_OPS.len())];
+                let vs2 = self.group_reg(input);
+                let (vd, vs1) = (input.below(32), input.below(32));
+                let mask = self.mask(input, vd as u32);
+                self.vector(format!("{}.vs v{}, v{}, v{}{}", op, vd, vs2, vs1, mask));
+            }
+            7 => {
+                let op = MASK_OPS[input.below(MASK_OPS.len())];
+                let (vd, vs2, vs1) = (input.below(32), input.below(32), input.below(32));
+                self.vector(format!("{}.mm v{}, v{}, v{}", op, vd, vs2, vs1));
+            }
+            8 if self.sew <= 64 => {
+                let vd = self.group_reg(input);
+                let mask = self.mask(input, vd);
+                if input.byte() & 1 == 1 {
+                    self.vector(format!("vle{}.v v{}, (a6){}", self.sew, vd, mask));
+                } else {
+                    self.vector(format!("vse{}.v v{}, (a6){}", self.sew, vd, mask));
+                }
+            }
+            _ => {
+                let vxrm = input.below(4) as u32;
+                self.scalar(csrwi(CSR_VXRM, vxrm), format!("csrwi vxrm, {}", vxrm));
+            }
+        }
+    }
+}
+
+pub fn generate(data: &[u8]) -> Program {
+    let mut input = Input { data };
+    let mut gen = Generator {
+        code: Vec::new(),
+        listing: Vec::new(),
+        sew: 8,
+        group: 1,
+    };
+
+    // prologue, not listed
+    gen.code.push(lui(A0, SCALARS_ADDR));
+    for (i, rd) in (11..16).enumerate() {
+        gen.code.push(ld(rd, A0, i as i32 * 8));
+    }
+    gen.code.push(lui(A6, SCRATCH_ADDR));
+    gen.code.push(lui(T2, VREGS_ADDR));
+    gen.vector("vsetvli t0, zero, e8, m1, tu, mu".to_string());
+    for vd in [0, 8, 16, 24].iter() {
+        gen.vector(format!("vl8re8.v v{}, (t2)", vd));
+        gen.code.push(addi(T2, T2, 1024));
+        gen.code.push(addi(T2, T2, 1024));
+    }
+    gen.listing.clear();
+
+    while !input.is_empty() && gen.listing.len() < MAX_INSTRUCTIONS {
+        gen.instruction(&mut input);
+    }
+    let listing = gen.listing.clone();
+
+    // epilogue, vs8r.v doesn't depend on vtype
+    gen.code.push(lui(T2, DUMP_ADDR));
+    for vs3 in [0, 8, 16, 24].iter() {
+        gen.vector(format!("vs8r.v v{}, (t2)", vs3));
+        gen.code.push(addi(T2, T2, 1024));
+        gen.code.push(addi(T2, T2, 1024));
+    }
+    gen.code.push(csrr(T3, CSR_VL));
+    gen.code.push(csrr(T4, CSR_VTYPE));
+    gen.code.push(csrr(T5, CSR_VXSAT));
+    gen.code.push(sd(T3, T2, 0));
+    gen.code.push(sd(T4, T2, 8));
+    gen.code.push(sd(T5, T2, 16));
+    gen.code.push(addi(A0, ZERO, 0));
+    gen.code.push(addi(A7, ZERO, 93));
+    gen.code.push(ECALL);
+
+    // the initial state comes from the input too, stretched by xorshift
+    let mut seed = data.iter().fold(0x9E37_79B9_7F4A_7C15u64, |s, b| {
+        (s ^ *b as u64).wrapping_mul(0x100_0000_01B3)
+    });
+    let mut memory = vec![0u8; DATA_SIZE as usize];
+    for byte in memory[..(DUMP_ADDR - DATA_ADDR) as usize].iter_mut() {
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        *byte = seed as u8;
+    }
+    let code: Vec<u8> = gen.code.iter().flat_map(|w| w.to_le_bytes()).collect();
+    Program {
+        elf: elf(&code, &memory),
+        listing,
+    }
+}
+
+// ELF64 with an executable segment at CODE_ADDR and a writable one at
+// DATA_ADDR, no sections
+fn elf(code: &[u8], data: &[u8]) -> Vec<u8> {
+    const PAGE: u64 = 0x1000;
+    let code_offset = PAGE;
+    let data_offset = code_offset + (code.len() as u64 + PAGE - 1) / PAGE * PAGE;
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&[0x7F, b'E', b'L', b'F', 2, 1, 1, 0]);
+    out.extend_from_slice(&[0; 8]);
+    out.extend_from_slice(&2u16.to_le_bytes()); // ET_EXEC
+    out.extend_from_slice(&0xF3u16.to_le_bytes()); // EM_RISCV
+    out.extend_from_slice(&1u32.to_le_bytes());
+    out.extend_from_slice(&CODE_ADDR.to_le_bytes()); // entry
+    out.extend_from_slice(&64u64.to_le_bytes()); // program headers
+    out.extend_from_slice(&0u64.to_le_bytes()); // section headers
+    out.extend_from_slice(&0u32.to_le_bytes());
+    out.extend_from_slice(&64u16.to_le_bytes());
+    out.extend_from_slice(&56u16.to_le_bytes());
+    out.extend_from_slice(&2u16.to_le_bytes());
+    out.extend_from_slice(&64u16.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    // (flags, offset, address, size), PF_R | PF_X and PF_R | PF_W
+    for (flags, offset, addr, size) in [
+        (5u32, code_offset, CODE_ADDR, code.len() as u64),
+        (6u32, data_offset, DATA_ADDR, data.len() as u64),
+    ]
+    .iter()
+    {
+        out.extend_from_slice(&1u32.to_le_bytes()); // PT_LOAD
+        out.extend_from_slice(&flags.to_le_bytes());
+        out.extend_from_slice(&offset.to_le_bytes());
+        out.extend_from_slice(&addr.to_le_bytes());
+        out.extend_from_slice(&addr.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&size.to_le_bytes());
+        out.extend_from_slice(&PAGE.to_le_bytes());
+    }
+    out.resize(code_offset as usize, 0);
+    out.extend_from_slice(code);
+    out.resize(data_offset as usize, 0);
+    out.extend_from_slice(data);
+    out
+}